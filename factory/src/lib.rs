@@ -0,0 +1,102 @@
+#![no_std]
+
+elrond_wasm::imports!();
+
+use public_sale_mint::config::SaleConfig;
+use public_sale_mint::ProxyTrait as _;
+
+pub const ERR_SALE_CODE_NOT_SET: &str = "The sale contract code has not been set";
+pub const ERR_SALE_CODE_EMPTY: &str = "The sale contract code is empty";
+
+/// Deploys public sale contracts and keeps track of who requested them.
+#[elrond_wasm::derive::contract]
+pub trait PublicSaleFactory: public_sale_mint::validation::ValidationModule {
+    /// The compiled public-sale-mint contract deployed by `deploy_sale`.
+    #[view(getSaleCode)]
+    #[storage_mapper("sale_code")]
+    fn sale_code(&self) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Every deployed sale with its owner, in deployment order.
+    #[storage_mapper("sale_owners")]
+    fn sale_owners(&self) -> MapMapper<ManagedAddress, ManagedAddress>;
+
+    #[storage_mapper("sales_of")]
+    fn sales_of(&self, owner: &ManagedAddress) -> VecMapper<ManagedAddress>;
+
+    #[proxy]
+    fn sale_proxy(&self) -> public_sale_mint::Proxy<Self::Api>;
+
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint]
+    fn set_sale_code(&self, code: ManagedBuffer) {
+        require!(!code.is_empty(), ERR_SALE_CODE_EMPTY);
+
+        self.sale_code().set(&code);
+    }
+
+    /// The ownership of the deployed contract is transferred to the caller, who is then its
+    /// admin and can upgrade it.
+    #[endpoint]
+    fn deploy_sale(&self, config: SaleConfig<Self::Api>) -> ManagedAddress {
+        require!(!self.sale_code().is_empty(), ERR_SALE_CODE_NOT_SET);
+
+        // fail here rather than in the deployed contract, with the same errors
+        self.require_valid_sale_config(&config);
+
+        let caller = self.blockchain().get_caller();
+
+        let (sale_address, ()) = self
+            .sale_proxy()
+            .init(OptionalValue::Some(config))
+            .deploy_contract(&self.sale_code().get(), CodeMetadata::UPGRADEABLE);
+
+        self.send()
+            .change_owner_address(sale_address.clone(), &caller)
+            .execute_on_dest_context_ignore_result();
+
+        self.sale_owners()
+            .insert(sale_address.clone(), caller.clone());
+        self.sales_of(&caller).push(&sale_address);
+
+        self.sale_deployed_event(&sale_address, &caller);
+
+        return sale_address;
+    }
+
+    #[view(getDeployedSales)]
+    fn get_deployed_sales(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, ManagedAddress>> {
+        let mut sales = MultiValueEncoded::new();
+
+        for (sale_address, owner) in self.sale_owners().iter() {
+            sales.push(MultiValue2::from((sale_address, owner)));
+        }
+
+        return sales;
+    }
+
+    #[view(getSalesOf)]
+    fn get_sales_of(&self, owner: &ManagedAddress) -> MultiValueEncoded<ManagedAddress> {
+        let mut sales = MultiValueEncoded::new();
+
+        for sale_address in self.sales_of(owner).iter() {
+            sales.push(sale_address);
+        }
+
+        return sales;
+    }
+
+    #[view(getSaleOwner)]
+    fn get_sale_owner(&self, sale_address: &ManagedAddress) -> Option<ManagedAddress> {
+        return self.sale_owners().get(sale_address);
+    }
+
+    #[event("sale_deployed")]
+    fn sale_deployed_event(
+        &self,
+        #[indexed] sale_address: &ManagedAddress,
+        #[indexed] owner: &ManagedAddress,
+    );
+}
@@ -0,0 +1,348 @@
+use elrond_wasm::types::{Address, ManagedAddress, ManagedBuffer, ManagedVec};
+use elrond_wasm_debug::{
+    rust_biguint,
+    testing_framework::{BlockchainStateWrapper, ContractObjWrapper},
+    DebugApi,
+};
+use public_sale_factory::PublicSaleFactory;
+use public_sale_mint::config::SaleConfig;
+use std::path::PathBuf;
+use std::sync::Once;
+
+const WASM_PATH: &'static str = "output/public-sale-factory.wasm";
+/// The sale contract is registered under this mock code instead of its built wasm,
+/// the factory deploys it by setting the same code.
+const SALE_MOCK_CODE: &[u8] = b"public-sale-mint mock code";
+
+static WRITE_SALE_MOCK_CODE: Once = Once::new();
+
+fn sale_mock_code_path() -> PathBuf {
+    let path = std::env::temp_dir().join("public-sale-mint-mock.wasm");
+
+    WRITE_SALE_MOCK_CODE.call_once(|| {
+        std::fs::write(&path, SALE_MOCK_CODE).unwrap();
+    });
+
+    return path;
+}
+
+struct FactorySetup<ContractObjBuilder>
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_factory::ContractObj<DebugApi>,
+{
+    blockchain_wrapper: BlockchainStateWrapper,
+    owner_address: Address,
+    user: Address,
+    contract_wrapper:
+        ContractObjWrapper<public_sale_factory::ContractObj<DebugApi>, ContractObjBuilder>,
+}
+
+fn setup_factory<ContractObjBuilder>(
+    builder: ContractObjBuilder,
+) -> FactorySetup<ContractObjBuilder>
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_factory::ContractObj<DebugApi>,
+{
+    let rust_zero = rust_biguint!(0u64);
+    let mut blockchain_wrapper = BlockchainStateWrapper::new();
+    let owner_address = blockchain_wrapper.create_user_account(&rust_zero);
+    let user = blockchain_wrapper.create_user_account(&rust_zero);
+    let contract_wrapper =
+        blockchain_wrapper.create_sc_account(&rust_zero, Some(&owner_address), builder, WASM_PATH);
+
+    // registers the sale contract, so that its code can be deployed by the factory
+    blockchain_wrapper.create_sc_account(
+        &rust_zero,
+        None,
+        public_sale_mint::contract_obj,
+        sale_mock_code_path().to_str().unwrap(),
+    );
+
+    blockchain_wrapper
+        .execute_tx(&owner_address, &contract_wrapper, &rust_zero, |sc| {
+            sc.init();
+        })
+        .assert_ok();
+
+    return FactorySetup {
+        blockchain_wrapper,
+        owner_address,
+        user,
+        contract_wrapper,
+    };
+}
+
+impl<ContractObjBuilder> FactorySetup<ContractObjBuilder>
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_factory::ContractObj<DebugApi>,
+{
+    fn set_sale_code(&mut self) {
+        self.blockchain_wrapper
+            .execute_tx(
+                &self.owner_address,
+                &self.contract_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.set_sale_code(ManagedBuffer::from(SALE_MOCK_CODE));
+                },
+            )
+            .assert_ok();
+    }
+
+    fn deploy_sale(&mut self, caller: &Address) -> Address {
+        let mut sale_address = Address::zero();
+
+        self.blockchain_wrapper
+            .execute_tx(caller, &self.contract_wrapper, &rust_biguint!(0), |sc| {
+                sale_address = sc
+                    .deploy_sale(SaleConfig {
+                        max_per_wallet_first_whitelist: 3,
+                        max_per_wallet_second_whitelist: 3,
+                        max_per_wallet_public: 3,
+                        price_per_egg: ManagedVec::from(vec![1u64, 5u64, 10u64]),
+                        reduced_price_per_egg: ManagedVec::from(vec![1u64, 4u64, 9u64]),
+                        timestamp_public_sale: 50,
+                        second_whitelist_delta: 10,
+                        first_whitelist_delta: 20,
+                        sale_duration: 10,
+                        reserved_supply: 0,
+                        schedule: None,
+                    })
+                    .to_address();
+            })
+            .assert_ok();
+
+        return sale_address;
+    }
+}
+
+#[test]
+fn set_sale_code() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_sale_code(ManagedBuffer::from(b"sale code"));
+
+                assert_eq!(sc.sale_code().get(), ManagedBuffer::from(b"sale code"));
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn set_sale_code_while_not_owner() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_sale_code(ManagedBuffer::from(b"sale code"));
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn set_empty_sale_code() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_sale_code(ManagedBuffer::new());
+            },
+        )
+        .assert_user_error(public_sale_factory::ERR_SALE_CODE_EMPTY);
+}
+
+#[test]
+fn deploy_sale_without_code() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.deploy_sale(SaleConfig {
+                    max_per_wallet_first_whitelist: 3,
+                    max_per_wallet_second_whitelist: 3,
+                    max_per_wallet_public: 3,
+                    price_per_egg: ManagedVec::from(vec![1u64, 5u64, 10u64]),
+                    reduced_price_per_egg: ManagedVec::from(vec![1u64, 4u64, 9u64]),
+                    timestamp_public_sale: 50,
+                    second_whitelist_delta: 10,
+                    first_whitelist_delta: 20,
+                    sale_duration: 10,
+                    reserved_supply: 0,
+                    schedule: None,
+                });
+            },
+        )
+        .assert_user_error(public_sale_factory::ERR_SALE_CODE_NOT_SET);
+}
+
+#[test]
+fn deploy_sale_with_invalid_parameters() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_sale_code(ManagedBuffer::from(b"sale code"));
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.deploy_sale(SaleConfig {
+                    max_per_wallet_first_whitelist: 3,
+                    max_per_wallet_second_whitelist: 3,
+                    max_per_wallet_public: 3,
+                    price_per_egg: ManagedVec::from(vec![1u64, 5u64]),
+                    reduced_price_per_egg: ManagedVec::from(vec![1u64, 4u64, 9u64]),
+                    timestamp_public_sale: 50,
+                    second_whitelist_delta: 10,
+                    first_whitelist_delta: 20,
+                    sale_duration: 10,
+                    reserved_supply: 0,
+                    schedule: None,
+                });
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_INIT_PRICE_PER_EGG_DIFF);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.user,
+            &setup.contract_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.deploy_sale(SaleConfig {
+                    max_per_wallet_first_whitelist: 3,
+                    max_per_wallet_second_whitelist: 3,
+                    max_per_wallet_public: 3,
+                    price_per_egg: ManagedVec::from(vec![1u64, 5u64, 10u64]),
+                    reduced_price_per_egg: ManagedVec::from(vec![1u64, 4u64, 9u64]),
+                    timestamp_public_sale: 50,
+                    second_whitelist_delta: 20,
+                    first_whitelist_delta: 10,
+                    sale_duration: 10,
+                    reserved_supply: 0,
+                    schedule: None,
+                });
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_INIT_SECOND_WL_LESSER_THEN_FIRST);
+
+    let user = setup.user.clone();
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.sale_owners().len(), 0);
+            assert_eq!(sc.sales_of(&ManagedAddress::from_address(&user)).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn deploy_sale() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+    let user = setup.user.clone();
+
+    setup.set_sale_code();
+    let sale_address = setup.deploy_sale(&user);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let user = ManagedAddress::from_address(&user);
+            let sale_address = ManagedAddress::from_address(&sale_address);
+
+            let deployed_sales: Vec<(ManagedAddress<DebugApi>, ManagedAddress<DebugApi>)> = sc
+                .get_deployed_sales()
+                .into_iter()
+                .map(|sale| sale.into_tuple())
+                .collect();
+            assert_eq!(deployed_sales, vec![(sale_address.clone(), user.clone())]);
+
+            let sales_of: Vec<ManagedAddress<DebugApi>> =
+                sc.get_sales_of(&user).into_iter().collect();
+            assert_eq!(sales_of, vec![sale_address.clone()]);
+
+            assert_eq!(sc.get_sale_owner(&sale_address), Some(user));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn deploy_several_sales() {
+    let mut setup = setup_factory(public_sale_factory::contract_obj);
+    let user = setup.user.clone();
+    let other_user = setup.owner_address.clone();
+
+    setup.set_sale_code();
+    let first_sale = setup.deploy_sale(&user);
+    let other_sale = setup.deploy_sale(&other_user);
+    let second_sale = setup.deploy_sale(&user);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let user = ManagedAddress::from_address(&user);
+            let other_user = ManagedAddress::from_address(&other_user);
+            let first_sale = ManagedAddress::from_address(&first_sale);
+            let other_sale = ManagedAddress::from_address(&other_sale);
+            let second_sale = ManagedAddress::from_address(&second_sale);
+
+            let deployed_sales: Vec<(ManagedAddress<DebugApi>, ManagedAddress<DebugApi>)> = sc
+                .get_deployed_sales()
+                .into_iter()
+                .map(|sale| sale.into_tuple())
+                .collect();
+            assert_eq!(
+                deployed_sales,
+                vec![
+                    (first_sale.clone(), user.clone()),
+                    (other_sale.clone(), other_user.clone()),
+                    (second_sale.clone(), user.clone()),
+                ]
+            );
+
+            let sales_of: Vec<ManagedAddress<DebugApi>> =
+                sc.get_sales_of(&user).into_iter().collect();
+            assert_eq!(sales_of, vec![first_sale, second_sale]);
+
+            let sales_of: Vec<ManagedAddress<DebugApi>> =
+                sc.get_sales_of(&other_user).into_iter().collect();
+            assert_eq!(sales_of, vec![other_sale]);
+        })
+        .assert_ok();
+}
@@ -0,0 +1,19 @@
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![no_std]
+
+elrond_wasm_node::wasm_endpoints! {
+    public_sale_factory
+    (
+        deploy_sale
+        getDeployedSales
+        getSaleCode
+        getSaleOwner
+        getSalesOf
+        set_sale_code
+    )
+}
+
+elrond_wasm_node::wasm_empty_callback! {}
@@ -1 +1,2 @@
-cargo test --package public-sale-mint --test *_tests
\ No newline at end of file
+cargo test --package public-sale-mint --test *_tests
+cargo test --package public-sale-factory
\ No newline at end of file
@@ -0,0 +1,108 @@
+elrond_wasm::imports!();
+
+use crate::roles::Role;
+
+pub const ERR_NOT_PENDING_ADMIN: &str = "Only the proposed admin can accept the admin rights";
+
+#[elrond_wasm::module]
+pub trait AdminModule {
+    /// Defaults to the owner of the contract until a transfer is accepted.
+    #[storage_mapper("admin")]
+    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPendingAdmin)]
+    #[storage_mapper("pending_admin")]
+    fn pending_admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Kept here so that the admin endpoints accept the members of the Admin role.
+    #[storage_mapper("role_members")]
+    fn role_members(&self, role: &Role) -> SetMapper<ManagedAddress>;
+
+    /// Only the admin itself can hand over the admin rights.
+    #[endpoint]
+    fn propose_admin(&self, address: ManagedAddress) {
+        self.require_contract_admin();
+
+        self.pending_admin().set(&address);
+
+        self.admin_proposed_event(&self.get_admin(), &address);
+    }
+
+    #[endpoint]
+    fn cancel_admin_proposal(&self) {
+        self.require_contract_admin();
+
+        if self.pending_admin().is_empty() {
+            return;
+        }
+
+        let proposed_admin = self.pending_admin().get();
+        self.pending_admin().clear();
+
+        self.admin_proposal_cancelled_event(&self.get_admin(), &proposed_admin);
+    }
+
+    #[endpoint]
+    fn accept_admin(&self) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            !self.pending_admin().is_empty() && self.pending_admin().get() == caller,
+            ERR_NOT_PENDING_ADMIN
+        );
+
+        let previous_admin = self.get_admin();
+
+        self.admin().set(&caller);
+        self.pending_admin().clear();
+
+        self.admin_transferred_event(&previous_admin, &caller);
+    }
+
+    #[view(getAdmin)]
+    fn get_admin(&self) -> ManagedAddress {
+        if self.admin().is_empty() {
+            return self.blockchain().get_owner_address();
+        }
+
+        return self.admin().get();
+    }
+
+    /// The members of the Admin role can call every admin endpoint.
+    fn require_admin(&self) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            caller == self.get_admin() || self.role_members(&Role::Admin).contains(&caller),
+            crate::ERR_NOT_OWNER
+        );
+    }
+
+    fn require_contract_admin(&self) {
+        require!(
+            self.blockchain().get_caller() == self.get_admin(),
+            crate::ERR_NOT_OWNER
+        );
+    }
+
+    #[event("admin_proposed")]
+    fn admin_proposed_event(
+        &self,
+        #[indexed] admin: &ManagedAddress,
+        #[indexed] proposed_admin: &ManagedAddress,
+    );
+
+    #[event("admin_proposal_cancelled")]
+    fn admin_proposal_cancelled_event(
+        &self,
+        #[indexed] admin: &ManagedAddress,
+        #[indexed] proposed_admin: &ManagedAddress,
+    );
+
+    #[event("admin_transferred")]
+    fn admin_transferred_event(
+        &self,
+        #[indexed] previous_admin: &ManagedAddress,
+        #[indexed] new_admin: &ManagedAddress,
+    );
+}
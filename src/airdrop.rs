@@ -0,0 +1,41 @@
+elrond_wasm::imports!();
+
+pub const ERR_AIRDROP_EMPTY: &str = "There is no recipient to airdrop to";
+pub const ERR_AIRDROP_ZERO_AMOUNT: &str = "Every recipient must receive at least one egg";
+pub const ERR_AIRDROP_NOT_ENOUGH_EGGS: &str = "Not enough eggs left to airdrop";
+
+#[elrond_wasm::module]
+pub trait AirdropModule {
+    #[storage_mapper("airdropped")]
+    fn airdropped(&self, sale_id: u64) -> MapMapper<ManagedAddress, u64>;
+
+    #[view(getAirdroppedAmount)]
+    fn get_airdropped_amount(&self, sale_id: u64, address: &ManagedAddress) -> u64 {
+        return self.airdropped(sale_id).get(address).unwrap_or_default();
+    }
+
+    #[view(getAllAirdrops)]
+    fn get_all_airdrops(
+        &self,
+        sale_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut airdrops = MultiValueEncoded::new();
+
+        for (address, amount) in self.airdropped(sale_id).iter() {
+            airdrops.push(MultiValue2::from((address, amount)));
+        }
+
+        return airdrops;
+    }
+
+    fn record_airdrop(&self, sale_id: u64, to: &ManagedAddress, amount: u64) {
+        let already_airdropped = self.get_airdropped_amount(sale_id, to);
+        self.airdropped(sale_id)
+            .insert(to.clone(), already_airdropped + amount);
+
+        self.airdrop_event(sale_id, to, amount);
+    }
+
+    #[event("airdrop")]
+    fn airdrop_event(&self, #[indexed] sale_id: u64, #[indexed] to: &ManagedAddress, amount: u64);
+}
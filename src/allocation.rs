@@ -0,0 +1,84 @@
+elrond_wasm::imports!();
+
+use crate::phase::SalePhase;
+
+pub const ERR_ALLOCATION_NOT_SELLING_PHASE: &str =
+    "An allocation can only be set for the holder, a whitelist or the public phase";
+pub const ERR_ALLOCATION_EXCEEDED: &str = "Sorry, the allocation of this phase is exhausted.";
+
+#[elrond_wasm::module]
+pub trait AllocationModule: crate::admin::AdminModule {
+    #[storage_mapper("phase_allocation")]
+    fn phase_allocation(&self, sale_id: u64, phase: &SalePhase) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("phase_sold")]
+    fn phase_sold(&self, sale_id: u64, phase: &SalePhase) -> SingleValueMapper<u64>;
+
+    #[endpoint]
+    fn set_phase_allocation(&self, sale_id: u64, phase: SalePhase, allocation: u64) {
+        self.require_admin();
+
+        require!(phase.is_selling(), ERR_ALLOCATION_NOT_SELLING_PHASE);
+
+        self.phase_allocation(sale_id, &phase).set(allocation);
+    }
+
+    #[endpoint]
+    fn remove_phase_allocation(&self, sale_id: u64, phase: SalePhase) {
+        self.require_admin();
+
+        self.phase_allocation(sale_id, &phase).clear();
+    }
+
+    /// Returns None if the phase has no cap.
+    /// The unsold allocation of a capped phase rolls over to the next phases.
+    #[view(getRemainingAllocation)]
+    fn get_remaining_allocation(&self, sale_id: u64, phase: SalePhase) -> Option<u64> {
+        if self.phase_allocation(sale_id, &phase).is_empty() {
+            return None;
+        }
+
+        let mut remaining = 0u64;
+
+        for previous in SalePhase::SELLING.iter() {
+            if !self.phase_allocation(sale_id, previous).is_empty() {
+                remaining = (remaining + self.phase_allocation(sale_id, previous).get())
+                    .saturating_sub(self.phase_sold(sale_id, previous).get());
+            }
+
+            if previous == &phase {
+                return Some(remaining);
+            }
+        }
+
+        return None;
+    }
+
+    #[view(getRemainingAllocations)]
+    fn get_remaining_allocations(
+        &self,
+        sale_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<SalePhase, Option<u64>>> {
+        let mut allocations = MultiValueEncoded::new();
+
+        for phase in SalePhase::SELLING.iter() {
+            let remaining = self.get_remaining_allocation(sale_id, *phase);
+            allocations.push(MultiValue2::from((*phase, remaining)));
+        }
+
+        return allocations;
+    }
+
+    fn consume_allocation(&self, sale_id: u64, phase: SalePhase, amount: u64) {
+        if !phase.is_selling() {
+            return;
+        }
+
+        if let Some(remaining) = self.get_remaining_allocation(sale_id, phase) {
+            require!(amount <= remaining, ERR_ALLOCATION_EXCEEDED);
+        }
+
+        self.phase_sold(sale_id, &phase)
+            .update(|sold| *sold += amount);
+    }
+}
@@ -0,0 +1,64 @@
+elrond_wasm::imports!();
+
+pub const ERR_CALLER_IS_SMART_CONTRACT: &str = "Sorry, smart contracts can't buy eggs.";
+pub const ERR_BLOCK_LIMIT_REACHED: &str = "Sorry, too many eggs were sold in this block.";
+pub const ERR_PURCHASE_TOO_SOON: &str = "Sorry, you must wait before buying again.";
+
+#[elrond_wasm::module]
+pub trait AntiBotModule: crate::admin::AdminModule {
+    /// Zero means no limit.
+    #[view]
+    #[storage_mapper("max_per_block")]
+    fn max_per_block(&self) -> SingleValueMapper<u64>;
+
+    /// Minimal delay between two purchases of the same address, in seconds.
+    #[view]
+    #[storage_mapper("purchase_cooldown")]
+    fn purchase_cooldown(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("current_block_nonce")]
+    fn current_block_nonce(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("current_block_sold")]
+    fn current_block_sold(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_purchase")]
+    fn last_purchase(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[endpoint]
+    fn set_anti_bot_limits(&self, max_per_block: u64, purchase_cooldown: u64) {
+        self.require_admin();
+
+        self.max_per_block().set(max_per_block);
+        self.purchase_cooldown().set(purchase_cooldown);
+    }
+
+    fn check_anti_bot(&self, caller: &ManagedAddress, to_buy: u64) {
+        require!(
+            !self.blockchain().is_smart_contract(caller),
+            ERR_CALLER_IS_SMART_CONTRACT
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        let last_purchase = self.last_purchase(caller);
+        require!(
+            last_purchase.is_empty() || now >= last_purchase.get() + self.purchase_cooldown().get(),
+            ERR_PURCHASE_TOO_SOON
+        );
+        last_purchase.set(now);
+
+        let block_nonce = self.blockchain().get_block_nonce();
+        if self.current_block_nonce().get() != block_nonce {
+            self.current_block_nonce().set(block_nonce);
+            self.current_block_sold().clear();
+        }
+
+        let max_per_block = self.max_per_block().get();
+        let block_sold = self.current_block_sold().get() + to_buy;
+        require!(
+            max_per_block == 0 || block_sold <= max_per_block,
+            ERR_BLOCK_LIMIT_REACHED
+        );
+        self.current_block_sold().set(block_sold);
+    }
+}
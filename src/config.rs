@@ -0,0 +1,28 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// The whitelists open `*_whitelist_delta` seconds before the public sale,
+/// which lasts `sale_duration` seconds.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct SaleConfig<M: ManagedTypeApi> {
+    pub max_per_wallet_first_whitelist: u64,
+    pub max_per_wallet_second_whitelist: u64,
+    pub max_per_wallet_public: u64,
+    pub price_per_egg: ManagedVec<M, BigUint<M>>,
+    pub reduced_price_per_egg: ManagedVec<M, BigUint<M>>,
+    pub timestamp_public_sale: u64,
+    pub second_whitelist_delta: u64,
+    pub first_whitelist_delta: u64,
+    pub sale_duration: u64,
+    pub reserved_supply: u64,
+    /// When set, replaces `timestamp_public_sale`, the deltas and `sale_duration`.
+    pub schedule: Option<SaleSchedule>,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct SaleSchedule {
+    pub timestamp_first_whitelist: u64,
+    pub timestamp_second_whitelist: u64,
+    pub timestamp_public_sale: u64,
+    pub timestamp_sale_closed: u64,
+}
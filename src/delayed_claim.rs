@@ -0,0 +1,73 @@
+elrond_wasm::imports!();
+
+pub const ERR_CLAIM_NOT_OPEN: &str = "Sorry, the eggs can't be claimed yet.";
+pub const ERR_NOTHING_TO_CLAIM: &str = "There is no egg to claim.";
+pub const ERR_CLAIM_BEFORE_CLOSE: &str = "The eggs can only be claimed once the sale is closed";
+pub const ERR_CLAIM_EGGS_PENDING: &str =
+    "The claim can't be undelayed while some eggs are waiting to be claimed";
+
+#[elrond_wasm::module]
+pub trait DelayedClaimModule: crate::admin::AdminModule {
+    /// When set, purchases are only recorded and the eggs are claimed from this timestamp.
+    #[view]
+    #[storage_mapper("claim_timestamp")]
+    fn claim_timestamp(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("pending_eggs")]
+    fn pending_eggs(&self, sale_id: u64) -> MapMapper<ManagedAddress, u64>;
+
+    #[view(getTotalPendingEggs)]
+    #[storage_mapper("total_pending_eggs")]
+    fn total_pending_eggs(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getPendingEggs)]
+    fn get_pending_eggs(&self, sale_id: u64, address: &ManagedAddress) -> u64 {
+        return self.pending_eggs(sale_id).get(address).unwrap_or_default();
+    }
+
+    #[view(getAllPendingEggs)]
+    fn get_all_pending_eggs(
+        &self,
+        sale_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut pending = MultiValueEncoded::new();
+
+        for (address, amount) in self.pending_eggs(sale_id).iter() {
+            pending.push(MultiValue2::from((address, amount)));
+        }
+
+        return pending;
+    }
+
+    fn is_claim_delayed(&self, sale_id: u64) -> bool {
+        return !self.claim_timestamp(sale_id).is_empty();
+    }
+
+    fn record_pending_eggs(&self, sale_id: u64, address: &ManagedAddress, amount: u64) {
+        let pending = self.get_pending_eggs(sale_id, address);
+        self.pending_eggs(sale_id)
+            .insert(address.clone(), pending + amount);
+
+        self.total_pending_eggs(sale_id)
+            .update(|total| *total += amount);
+    }
+
+    fn take_pending_eggs(&self, sale_id: u64, address: &ManagedAddress) -> u64 {
+        let now = self.blockchain().get_block_timestamp();
+        require!(
+            self.is_claim_delayed(sale_id) && now >= self.claim_timestamp(sale_id).get(),
+            ERR_CLAIM_NOT_OPEN
+        );
+
+        let pending = self
+            .pending_eggs(sale_id)
+            .remove(address)
+            .unwrap_or_default();
+        require!(pending > 0, ERR_NOTHING_TO_CLAIM);
+
+        self.total_pending_eggs(sale_id)
+            .update(|total| *total -= pending);
+
+        return pending;
+    }
+}
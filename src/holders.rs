@@ -0,0 +1,162 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use crate::revenue::TOTAL_SHARES;
+
+pub const ERR_HOLDER_SALE_NOT_CONFIGURED: &str = "There is no holder sale";
+pub const ERR_HOLDER_SALE_AFTER_PUBLIC: &str = "The holder sale must open before the public sale";
+pub const ERR_HOLDER_NO_PROOF: &str = "Send at least one token of the collection as proof";
+pub const ERR_HOLDER_BAD_TOKEN: &str = "The token sent is not from the holder collection";
+pub const ERR_HOLDER_TOKEN_ALREADY_USED: &str =
+    "This token has already proven the holding of another wallet";
+pub const ERR_HOLDER_DISCOUNT_TOO_HIGH: &str = "A discount can't be greater than 10000";
+pub const ERR_HOLDER_DISCOUNTS_NOT_SORTED: &str =
+    "The discounts must be sorted by strictly increasing held amount";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct HolderDiscount {
+    pub min_held: u64,
+    /// In basis points.
+    pub discount: u64,
+}
+
+#[elrond_wasm::module]
+pub trait HoldersModule: crate::admin::AdminModule {
+    #[view]
+    #[storage_mapper("holder_token")]
+    fn holder_token(&self, sale_id: u64) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view]
+    #[storage_mapper("timestamp_holder_sale")]
+    fn timestamp_holder_sale(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    /// The number of tokens of the collection presented by each holder.
+    #[storage_mapper("holder_proofs")]
+    fn holder_proofs(&self, sale_id: u64) -> MapMapper<ManagedAddress, u64>;
+
+    /// The wallet each token of the collection has proven the holding of.
+    #[storage_mapper("holder_proof_tokens")]
+    fn holder_proof_tokens(&self, sale_id: u64) -> MapMapper<u64, ManagedAddress>;
+
+    /// When set, holders pay the price list of their tier minus the discount of their holding,
+    /// instead of the reduced prices.
+    #[storage_mapper("holder_discounts")]
+    fn holder_discounts(&self) -> VecMapper<HolderDiscount>;
+
+    fn is_holder_sale_open(&self, sale_id: u64) -> bool {
+        let now = self.blockchain().get_block_timestamp();
+
+        return !self.timestamp_holder_sale(sale_id).is_empty()
+            && now >= self.timestamp_holder_sale(sale_id).get();
+    }
+
+    /// The tokens sent are given back in the same transaction.
+    /// A proof stays valid until the holder proves again, and each token proves a single wallet
+    /// per sale so that it can't be passed around.
+    #[endpoint]
+    #[payable("*")]
+    fn prove_holding(&self, sale_id: u64) {
+        require!(
+            !self.holder_token(sale_id).is_empty(),
+            ERR_HOLDER_SALE_NOT_CONFIGURED
+        );
+
+        let caller = self.blockchain().get_caller();
+        let holder_token = self.holder_token(sale_id).get();
+        let payments = self.call_value().all_esdt_transfers();
+
+        let mut held = 0u64;
+        for payment in payments.iter() {
+            require!(
+                payment.token_identifier == holder_token,
+                ERR_HOLDER_BAD_TOKEN
+            );
+
+            let proven_wallet = self.holder_proof_tokens(sale_id).get(&payment.token_nonce);
+            require!(
+                proven_wallet.is_none() || proven_wallet.unwrap() == caller,
+                ERR_HOLDER_TOKEN_ALREADY_USED
+            );
+            self.holder_proof_tokens(sale_id)
+                .insert(payment.token_nonce, caller.clone());
+
+            held += payment.amount.to_u64().unwrap_or_default();
+
+            self.send().direct(
+                &caller,
+                &payment.token_identifier,
+                payment.token_nonce,
+                &payment.amount,
+                &[],
+            );
+        }
+
+        require!(held > 0, ERR_HOLDER_NO_PROOF);
+
+        self.holder_proofs(sale_id).insert(caller, held);
+    }
+
+    #[endpoint]
+    fn set_holder_discounts(
+        &self,
+        #[var_args] discounts: MultiValueEncoded<MultiValue2<u64, u64>>,
+    ) {
+        self.require_admin();
+
+        self.holder_discounts().clear();
+
+        let mut previous_min_held = 0u64;
+        for discount in discounts.into_iter() {
+            let (min_held, discount) = discount.into_tuple();
+
+            require!(
+                min_held > previous_min_held,
+                ERR_HOLDER_DISCOUNTS_NOT_SORTED
+            );
+            require!(discount <= TOTAL_SHARES, ERR_HOLDER_DISCOUNT_TOO_HIGH);
+
+            self.holder_discounts()
+                .push(&HolderDiscount { min_held, discount });
+            previous_min_held = min_held;
+        }
+    }
+
+    #[view(getHolderDiscounts)]
+    fn get_holder_discounts(&self) -> MultiValueEncoded<MultiValue2<u64, u64>> {
+        let mut discounts = MultiValueEncoded::new();
+
+        for discount in self.holder_discounts().iter() {
+            discounts.push(MultiValue2::from((discount.min_held, discount.discount)));
+        }
+
+        return discounts;
+    }
+
+    /// In basis points, the discount of the highest threshold reached.
+    #[view(getHolderDiscount)]
+    fn get_holder_discount(&self, held_amount: u64) -> u64 {
+        let mut holder_discount = 0u64;
+
+        for discount in self.holder_discounts().iter() {
+            if held_amount >= discount.min_held {
+                holder_discount = discount.discount;
+            }
+        }
+
+        return holder_discount;
+    }
+
+    fn apply_discount(&self, price: &BigUint, discount: u64) -> BigUint {
+        return price * (TOTAL_SHARES - discount) / TOTAL_SHARES;
+    }
+
+    #[view(getHeldAmount)]
+    fn get_held_amount(&self, sale_id: u64, address: &ManagedAddress) -> u64 {
+        return self.holder_proofs(sale_id).get(address).unwrap_or_default();
+    }
+
+    #[view(isVerifiedHolder)]
+    fn is_verified_holder(&self, sale_id: u64, address: &ManagedAddress) -> bool {
+        return self.get_held_amount(sale_id, address) > 0;
+    }
+}
@@ -2,137 +2,708 @@
 
 elrond_wasm::imports!();
 
-pub const ERR_INIT_PRICE_PER_EGG_DIFF: &str = "Price per egg length different from max per wallet";
+pub const ERR_INIT_PRICE_PER_EGG_DIFF: &str =
+    "Price per egg length different from the first whitelist and public max per wallet";
 pub const ERR_INIT_PRICE_PER_EGG_ZERO: &str = "The price list is empty";
 pub const ERR_INIT_REDUCED_PRICE_PER_EGG_DIFF: &str =
-    "Reduced rice per egg length different from max per wallet";
+    "Reduced rice per egg length different from the second whitelist max per wallet";
 pub const ERR_INIT_REDUCED_PRICE_PER_EGG_ZERO: &str = "The reduced price list is empty";
 pub const ERR_INIT_SECOND_WL_LESSER_THEN_FIRST: &str =
     "The second whitelist must be lesser or equal than the first";
+pub const ERR_INIT_ZERO_SUPPLY: &str = "At least one egg per wallet must be on sale";
+pub const ERR_INIT_FIRST_WL_UNDERFLOW: &str =
+    "The first whitelist would open before the timestamp 0";
+pub const ERR_INIT_CLOSE_BEFORE_OPEN: &str = "The sale must close after the public sale opens";
+pub const ERR_INIT_CLOSE_OVERFLOW: &str = "The sale closing timestamp overflows";
+pub const ERR_INIT_CONFIG_MISSING: &str = "The config of the first sale is missing";
+pub const ERR_SCHEDULE_SECOND_WL_BEFORE_FIRST: &str =
+    "The second whitelist can't open before the first one";
+pub const ERR_SCHEDULE_PUBLIC_BEFORE_SECOND_WL: &str =
+    "The public sale can't open before the second whitelist";
+pub const ERR_SCHEDULE_OVERFLOW: &str = "Shifting the schedule overflows a timestamp";
+pub const ERR_SCHEDULE_UNDERFLOW: &str = "The schedule can't be shifted before the timestamp 0";
+pub const ERR_SCHEDULE_SALE_STARTED: &str = "The schedule can't be shifted once the sale started";
 
+pub mod admin;
+pub mod airdrop;
+pub mod allocation;
+pub mod anti_bot;
+pub mod config;
+pub mod delayed_claim;
+pub mod holders;
+pub mod migration;
+pub mod mint;
+pub mod multisig;
+pub mod overrides;
+pub mod pause;
+pub mod phase;
+pub mod promo;
+pub mod referral;
+pub mod reserve;
+pub mod revenue;
+pub mod roles;
+pub mod validation;
 pub mod whitelist;
 
+use config::{SaleConfig, SaleSchedule};
+use multisig::WithdrawalAsset;
+use phase::SalePhase;
+use roles::Role;
+use whitelist::BuyerTier;
+
 pub const ERR_NOT_OWNER: &str = "Endpoint can only be called by owner";
 pub const ERR_FILL_BAD_NONCE: &str =
     "The nonce you are trying to fill the SC with is not the one expected";
 pub const ERR_FILL_BAD_IDENTIFIER: &str =
     "The identifier of the token you are trying to fill is not the one expected";
 pub const ERR_BAD_AMOUNT_SENT: &str = "Unrecognized amount of eGLD sent.";
+pub const ERR_MAX_PER_WALLET_EXCEEDED: &str = "Sorry, you can't buy more eggs with this wallet.";
 
 pub const ERR_BUY_NOT_EGLD: &str = "Sorry, the payment is not in eGLD.";
 pub const ERR_SOLD_OUT: &str = "Sorry, all the eggs has been sold.";
 pub const ERR_SALE_CLOSED: &str = "Sorry, the sale is closed.";
 pub const ERR_SALE_NOT_OPEN: &str = "Sorry, the sale is not open.";
+pub const ERR_COLLECTION_ALREADY_ISSUED: &str = "The egg collection has already been issued";
+pub const ERR_COLLECTION_BEING_ISSUED: &str = "The egg collection is being issued";
+pub const ERR_COLLECTION_NOT_ISSUED: &str = "The egg collection has not been issued yet";
+pub const ERR_EGG_NONCE_ALREADY_CREATED: &str = "The egg nonce has already been created";
+pub const ERR_EGG_NONCE_NOT_CREATED: &str = "The egg nonce has not been created yet";
+pub const ERR_EGG_ZERO_QUANTITY: &str = "At least one egg must be created";
+pub const ERR_SALE_NOT_FOUND: &str = "There is no such sale";
+pub const ERR_REVEAL_NOT_COVERED: &str =
+    "The revealed nonce doesn't cover the eggs to claim and the team reserve";
 
 #[elrond_wasm::derive::contract]
-pub trait PublicSaleMint: whitelist::WhitelistModule {
+pub trait PublicSaleMint:
+    whitelist::WhitelistModule
+    + admin::AdminModule
+    + airdrop::AirdropModule
+    + anti_bot::AntiBotModule
+    + allocation::AllocationModule
+    + delayed_claim::DelayedClaimModule
+    + holders::HoldersModule
+    + migration::MigrationModule
+    + mint::MintModule
+    + multisig::MultisigModule
+    + overrides::OverridesModule
+    + pause::PauseModule
+    + promo::PromoModule
+    + referral::ReferralModule
+    + reserve::ReserveModule
+    + revenue::RevenueModule
+    + roles::RolesModule
+    + validation::ValidationModule
+{
+    /// The sales are numbered from 1, the first one being configured on deployment.
+    #[view(getLastSaleId)]
+    #[storage_mapper("last_sale_id")]
+    fn last_sale_id(&self) -> SingleValueMapper<u64>;
+
     #[view]
     #[storage_mapper("max_per_wallet")]
-    fn max_per_wallet(&self) -> SingleValueMapper<u64>;
+    fn max_per_wallet(&self, sale_id: u64, tier: &BuyerTier) -> SingleValueMapper<u64>;
 
     #[view]
     #[storage_mapper("price_per_egg")]
-    fn price_per_egg(&self) -> VecMapper<BigUint>;
+    fn price_per_egg(&self, sale_id: u64) -> VecMapper<BigUint>;
 
     #[view]
     #[storage_mapper("reduced_price_per_egg")]
-    fn reduced_price_per_egg(&self) -> VecMapper<BigUint>;
+    fn reduced_price_per_egg(&self, sale_id: u64) -> VecMapper<BigUint>;
 
     #[storage_mapper("token_identifier")]
-    fn token_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
+    fn token_identifier(&self, sale_id: u64) -> SingleValueMapper<TokenIdentifier>;
 
     #[storage_mapper("token_nonce")]
-    fn token_nonce(&self) -> SingleValueMapper<u64>;
+    fn token_nonce(&self, sale_id: u64) -> SingleValueMapper<u64>;
 
-    #[storage_mapper("already_bought")]
-    fn already_bought(&self) -> MapMapper<ManagedAddress, u64>;
+    /// Set while the issue of the collection waits for its callback.
+    #[storage_mapper("collection_issuing")]
+    fn collection_issuing(&self, sale_id: u64) -> SingleValueMapper<bool>;
 
     #[view]
     #[storage_mapper("timestamp_sale_closed")]
-    fn timestamp_sale_closed(&self) -> SingleValueMapper<u64>;
+    fn timestamp_sale_closed(&self, sale_id: u64) -> SingleValueMapper<u64>;
 
+    /// The config of the first sale is required on deploy, an upgrade is run without it.
     #[init]
-    fn init(
-        &self,
-        max_per_wallet: u64,
-        price_per_egg: ManagedVec<BigUint>,
-        reduced_price_per_egg: ManagedVec<BigUint>,
-        timestamp_public_sale: u64,
-        second_whitelist_delta: u64,
-        first_whitelist_delta: u64,
-        token: TokenIdentifier,
-        token_nonce: u64,
-        sale_duration: u64,
-    ) {
-        require!(price_per_egg.len() > 0, ERR_INIT_PRICE_PER_EGG_ZERO);
+    fn init(&self, #[var_args] config: OptionalValue<SaleConfig<Self::Api>>) {
+        // elrond-wasm 0.30 has no dedicated upgrade function, an upgrade runs init again
+        if self.last_sale_id().get() > 0 || self.has_unnumbered_sale() {
+            self.upgrade_storage();
+            return;
+        }
+
+        let config = config.into_option();
+        require!(config.is_some(), ERR_INIT_CONFIG_MISSING);
+
+        self.storage_version().set(migration::STORAGE_VERSION);
+
+        self.create_sale_internal(config.unwrap());
+    }
+
+    /// Also run by an upgrade, kept as an endpoint to retry a migration.
+    #[endpoint]
+    fn migrate(&self) {
+        self.require_admin();
+
+        self.upgrade_storage();
+    }
+
+    /// Moves the whitelisted addresses of a contract deployed before the sales were numbered
+    /// to the sale 1, the old whitelists can't be iterated.
+    #[endpoint]
+    fn migrate_whitelisted(&self, #[var_args] addresses: MultiValueEncoded<ManagedAddress>) {
+        self.require_admin();
+
+        for address in addresses.into_iter() {
+            if self.unnumbered_first_whitelist().contains(&address) {
+                self.first_whitelist_mapper(migration::UNNUMBERED_SALE_ID)
+                    .add(&address);
+                self.unnumbered_first_whitelist().remove(&address);
+            }
+
+            if self.unnumbered_second_whitelist().contains(&address) {
+                self.second_whitelist_mapper(migration::UNNUMBERED_SALE_ID)
+                    .add(&address);
+                self.unnumbered_second_whitelist().remove(&address);
+            }
+        }
+    }
+
+    fn upgrade_storage(&self) {
+        if self.last_sale_id().is_empty() && self.has_unnumbered_sale() {
+            self.number_unnumbered_sale();
+        }
+
+        self.migrate_storage(self.last_sale_id().get());
+    }
+
+    /// Moves the sale of a contract deployed before the sales were numbered to its sale id.
+    /// Its buyers are left to the version 1 step of `migrate_storage`.
+    fn number_unnumbered_sale(&self) {
+        let sale_id = migration::UNNUMBERED_SALE_ID;
+        self.last_sale_id().set(sale_id);
+
+        let max_per_wallet = self.unnumbered_max_per_wallet().get();
+        self.unnumbered_max_per_wallet().clear();
+        self.max_per_wallet(sale_id, &BuyerTier::FirstWhitelist)
+            .set(max_per_wallet);
+        self.max_per_wallet(sale_id, &BuyerTier::SecondWhitelist)
+            .set(max_per_wallet);
+        self.max_per_wallet(sale_id, &BuyerTier::Public)
+            .set(max_per_wallet);
+
+        for price in self.unnumbered_price_per_egg().iter() {
+            self.price_per_egg(sale_id).push(&price);
+        }
+        self.unnumbered_price_per_egg().clear();
+
+        for price in self.unnumbered_reduced_price_per_egg().iter() {
+            self.reduced_price_per_egg(sale_id).push(&price);
+        }
+        self.unnumbered_reduced_price_per_egg().clear();
+
+        self.token_identifier(sale_id)
+            .set(self.unnumbered_token_identifier().get());
+        self.unnumbered_token_identifier().clear();
+        self.token_nonce(sale_id)
+            .set(self.unnumbered_token_nonce().get());
+        self.unnumbered_token_nonce().clear();
+
+        self.timestamp_first_whitelist(sale_id)
+            .set(self.unnumbered_timestamp_first_whitelist().get());
+        self.unnumbered_timestamp_first_whitelist().clear();
+        self.timestamp_second_whitelist(sale_id)
+            .set(self.unnumbered_timestamp_second_whitelist().get());
+        self.unnumbered_timestamp_second_whitelist().clear();
+        self.timestamp_public_sale(sale_id)
+            .set(self.unnumbered_timestamp_public_sale().get());
+        self.unnumbered_timestamp_public_sale().clear();
+        self.timestamp_sale_closed(sale_id)
+            .set(self.unnumbered_timestamp_sale_closed().get());
+        self.unnumbered_timestamp_sale_closed().clear();
+
+        for (address, bought) in self.unnumbered_already_bought().iter() {
+            self.legacy_already_bought(sale_id).insert(address, bought);
+        }
+        self.unnumbered_already_bought().clear();
+    }
+
+    /// Each sale has its own collection, issued with `issue_egg_collection`.
+    #[endpoint]
+    fn create_sale(&self, config: SaleConfig<Self::Api>) -> u64 {
+        self.require_admin();
+
+        return self.create_sale_internal(config);
+    }
+
+    fn create_sale_internal(&self, config: SaleConfig<Self::Api>) -> u64 {
+        self.require_valid_sale_config(&config);
+
+        let sale_id = self.last_sale_id().get() + 1;
+        self.last_sale_id().set(sale_id);
+
+        self.max_per_wallet(sale_id, &BuyerTier::FirstWhitelist)
+            .set(config.max_per_wallet_first_whitelist);
+        self.max_per_wallet(sale_id, &BuyerTier::SecondWhitelist)
+            .set(config.max_per_wallet_second_whitelist);
+        self.max_per_wallet(sale_id, &BuyerTier::Public)
+            .set(config.max_per_wallet_public);
+
+        for price in config.price_per_egg.iter() {
+            self.price_per_egg(sale_id).push(&price);
+        }
+
+        for price in config.reduced_price_per_egg.iter() {
+            self.reduced_price_per_egg(sale_id).push(&price);
+        }
+
+        let schedule = match config.schedule {
+            Some(schedule) => schedule,
+            None => SaleSchedule {
+                timestamp_first_whitelist: config.timestamp_public_sale
+                    - config.first_whitelist_delta,
+                timestamp_second_whitelist: config.timestamp_public_sale
+                    - config.second_whitelist_delta,
+                timestamp_public_sale: config.timestamp_public_sale,
+                timestamp_sale_closed: config.timestamp_public_sale + config.sale_duration,
+            },
+        };
+
+        self.timestamp_first_whitelist(sale_id)
+            .set(schedule.timestamp_first_whitelist);
+        self.timestamp_second_whitelist(sale_id)
+            .set(schedule.timestamp_second_whitelist);
+        self.timestamp_public_sale(sale_id)
+            .set(schedule.timestamp_public_sale);
+        self.timestamp_sale_closed(sale_id)
+            .set(schedule.timestamp_sale_closed);
+        self.reserved_supply(sale_id).set(config.reserved_supply);
+
+        return sale_id;
+    }
+
+    /// The current schedule is returned along with the whitelist deltas and the duration
+    /// derived from it.
+    #[view(getSaleConfig)]
+    fn get_sale_config(&self, sale_id: u64) -> SaleConfig<Self::Api> {
+        self.require_sale_exists(sale_id);
+
+        let timestamp_public_sale = self.timestamp_public_sale(sale_id).get();
+
+        let mut price_per_egg = ManagedVec::new();
+        for price in self.price_per_egg(sale_id).iter() {
+            price_per_egg.push(price);
+        }
+
+        let mut reduced_price_per_egg = ManagedVec::new();
+        for price in self.reduced_price_per_egg(sale_id).iter() {
+            reduced_price_per_egg.push(price);
+        }
+
+        return SaleConfig {
+            max_per_wallet_first_whitelist: self
+                .max_per_wallet(sale_id, &BuyerTier::FirstWhitelist)
+                .get(),
+            max_per_wallet_second_whitelist: self
+                .max_per_wallet(sale_id, &BuyerTier::SecondWhitelist)
+                .get(),
+            max_per_wallet_public: self.max_per_wallet(sale_id, &BuyerTier::Public).get(),
+            price_per_egg,
+            reduced_price_per_egg,
+            timestamp_public_sale,
+            second_whitelist_delta: timestamp_public_sale
+                - self.timestamp_second_whitelist(sale_id).get(),
+            first_whitelist_delta: timestamp_public_sale
+                - self.timestamp_first_whitelist(sale_id).get(),
+            sale_duration: self.timestamp_sale_closed(sale_id).get() - timestamp_public_sale,
+            reserved_supply: self.reserved_supply(sale_id).get(),
+            schedule: Some(self.get_sale_schedule(sale_id)),
+        };
+    }
+
+    #[view(getSaleSchedule)]
+    fn get_sale_schedule(&self, sale_id: u64) -> SaleSchedule {
+        self.require_sale_exists(sale_id);
+
+        return SaleSchedule {
+            timestamp_first_whitelist: self.timestamp_first_whitelist(sale_id).get(),
+            timestamp_second_whitelist: self.timestamp_second_whitelist(sale_id).get(),
+            timestamp_public_sale: self.timestamp_public_sale(sale_id).get(),
+            timestamp_sale_closed: self.timestamp_sale_closed(sale_id).get(),
+        };
+    }
+
+    /// The verified holders can buy from `timestamp_holder_sale`, which must be before the
+    /// public sale.
+    #[endpoint]
+    fn set_holder_sale(&self, sale_id: u64, token: TokenIdentifier, timestamp_holder_sale: u64) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
 
         require!(
-            reduced_price_per_egg.len() > 0,
-            ERR_INIT_REDUCED_PRICE_PER_EGG_ZERO
+            timestamp_holder_sale < self.timestamp_public_sale(sale_id).get(),
+            holders::ERR_HOLDER_SALE_AFTER_PUBLIC
         );
 
+        self.holder_token(sale_id).set(&token);
+        self.timestamp_holder_sale(sale_id)
+            .set(timestamp_holder_sale);
+    }
+
+    /// Without a timestamp, the eggs are sent on purchase again.
+    /// That's only possible while no egg is waiting to be claimed.
+    #[endpoint]
+    fn set_claim_timestamp(&self, sale_id: u64, #[var_args] claim_timestamp: OptionalValue<u64>) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
+        match claim_timestamp.into_option() {
+            Some(claim_timestamp) => {
+                require!(
+                    claim_timestamp >= self.timestamp_sale_closed(sale_id).get(),
+                    delayed_claim::ERR_CLAIM_BEFORE_CLOSE
+                );
+
+                self.claim_timestamp(sale_id).set(claim_timestamp);
+            }
+            None => {
+                require!(
+                    self.total_pending_eggs(sale_id).get() == 0,
+                    delayed_claim::ERR_CLAIM_EGGS_PENDING
+                );
+
+                self.claim_timestamp(sale_id).clear();
+            }
+        }
+    }
+
+    /// Moves every phase of the sale later, including the holder phase, the whitelist ends
+    /// and the claim of the delayed eggs.
+    #[endpoint]
+    fn postpone_sale(&self, sale_id: u64, delay: u64) {
+        self.shift_schedule(sale_id, delay, true);
+    }
+
+    /// Moves every phase of the sale earlier, including the holder phase, the whitelist ends
+    /// and the claim of the delayed eggs.
+    #[endpoint]
+    fn advance_sale(&self, sale_id: u64, advance: u64) {
+        self.shift_schedule(sale_id, advance, false);
+    }
+
+    fn shift_schedule(&self, sale_id: u64, offset: u64, postpone: bool) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
         require!(
-            price_per_egg.len() == max_per_wallet as usize,
-            ERR_INIT_PRICE_PER_EGG_DIFF
+            self.get_current_phase(sale_id) == SalePhase::NotStarted,
+            ERR_SCHEDULE_SALE_STARTED
         );
 
+        self.timestamp_first_whitelist(sale_id)
+            .update(|timestamp| *timestamp = self.shift_timestamp(*timestamp, offset, postpone));
+        self.timestamp_second_whitelist(sale_id)
+            .update(|timestamp| *timestamp = self.shift_timestamp(*timestamp, offset, postpone));
+        self.timestamp_public_sale(sale_id)
+            .update(|timestamp| *timestamp = self.shift_timestamp(*timestamp, offset, postpone));
+        self.timestamp_sale_closed(sale_id)
+            .update(|timestamp| *timestamp = self.shift_timestamp(*timestamp, offset, postpone));
+
+        let optional_timestamps = [
+            self.timestamp_holder_sale(sale_id),
+            self.timestamp_first_whitelist_end(sale_id),
+            self.timestamp_second_whitelist_end(sale_id),
+            self.claim_timestamp(sale_id),
+        ];
+        for timestamp_mapper in optional_timestamps.iter() {
+            if !timestamp_mapper.is_empty() {
+                timestamp_mapper.update(|timestamp| {
+                    *timestamp = self.shift_timestamp(*timestamp, offset, postpone)
+                });
+            }
+        }
+
+        self.schedule_shifted_event(sale_id, &self.get_sale_schedule(sale_id));
+    }
+
+    fn shift_timestamp(&self, timestamp: u64, offset: u64, postpone: bool) -> u64 {
+        if postpone {
+            let shifted = timestamp.checked_add(offset);
+            require!(shifted.is_some(), ERR_SCHEDULE_OVERFLOW);
+
+            return shifted.unwrap();
+        }
+
+        let shifted = timestamp.checked_sub(offset);
+        require!(shifted.is_some(), ERR_SCHEDULE_UNDERFLOW);
+
+        return shifted.unwrap();
+    }
+
+    #[event("schedule_shifted")]
+    fn schedule_shifted_event(&self, #[indexed] sale_id: u64, schedule: &SaleSchedule);
+
+    fn require_sale_exists(&self, sale_id: u64) {
         require!(
-            reduced_price_per_egg.len() == max_per_wallet as usize,
-            ERR_INIT_REDUCED_PRICE_PER_EGG_DIFF
+            sale_id > 0 && sale_id <= self.last_sale_id().get(),
+            ERR_SALE_NOT_FOUND
         );
+    }
 
+    #[view(getSales)]
+    fn get_sales(&self) -> MultiValueEncoded<MultiValue2<u64, SalePhase>> {
+        let mut sales = MultiValueEncoded::new();
+
+        for sale_id in 1..=self.last_sale_id().get() {
+            sales.push(MultiValue2::from((
+                sale_id,
+                self.get_current_phase(sale_id),
+            )));
+        }
+
+        return sales;
+    }
+
+    /// The token identifier is stored by the callback.
+    #[endpoint]
+    #[payable("EGLD")]
+    fn issue_egg_collection(
+        &self,
+        #[payment] issue_cost: BigUint,
+        sale_id: u64,
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
+        require!(
+            self.token_identifier(sale_id).is_empty(),
+            ERR_COLLECTION_ALREADY_ISSUED
+        );
         require!(
-            second_whitelist_delta <= first_whitelist_delta,
-            ERR_INIT_SECOND_WL_LESSER_THEN_FIRST
+            !self.collection_issuing(sale_id).get(),
+            ERR_COLLECTION_BEING_ISSUED
         );
 
-        self.max_per_wallet().set(max_per_wallet);
+        self.collection_issuing(sale_id).set(true);
 
-        for price in price_per_egg.iter() {
-            self.price_per_egg().push(&price);
-        }
+        self.send()
+            .esdt_system_sc_proxy()
+            .issue_semi_fungible(
+                issue_cost,
+                &token_display_name,
+                &token_ticker,
+                SemiFungibleTokenProperties {
+                    can_freeze: false,
+                    can_wipe: false,
+                    can_pause: false,
+                    can_change_owner: false,
+                    can_upgrade: true,
+                    can_add_special_roles: true,
+                },
+            )
+            .async_call()
+            .with_callback(self.callbacks().issue_callback(sale_id))
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn issue_callback(
+        &self,
+        sale_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        self.collection_issuing(sale_id).clear();
 
-        for price in reduced_price_per_egg.iter() {
-            self.reduced_price_per_egg().push(&price);
+        match result {
+            ManagedAsyncCallResult::Ok(token_identifier) => {
+                self.token_identifier(sale_id).set(&token_identifier);
+            }
+            ManagedAsyncCallResult::Err(_) => {
+                // the issue cost is given back
+                let returned = self.call_value().egld_value();
+                if returned > 0 {
+                    let admin = self.get_admin();
+                    self.send().direct_egld(&admin, &returned, &[]);
+                }
+            }
         }
+    }
+
+    /// Gives the contract the roles to create the egg nonce and to mint on demand.
+    #[endpoint]
+    fn set_egg_roles(&self, sale_id: u64) {
+        self.require_admin();
 
-        self.timestamp_public_sale().set(timestamp_public_sale);
-        self.timestamp_second_whitelist()
-            .set(timestamp_public_sale - second_whitelist_delta);
-        self.timestamp_first_whitelist()
-            .set(timestamp_public_sale - first_whitelist_delta);
-        self.token_identifier().set(token);
-        self.token_nonce().set(token_nonce);
-        self.timestamp_sale_closed()
-            .set(timestamp_public_sale + sale_duration);
+        require!(
+            !self.token_identifier(sale_id).is_empty(),
+            ERR_COLLECTION_NOT_ISSUED
+        );
+
+        let roles = [EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity];
+
+        self.send()
+            .esdt_system_sc_proxy()
+            .set_special_roles(
+                &self.blockchain().get_sc_address(),
+                &self.token_identifier(sale_id).get(),
+                roles[..].iter().cloned(),
+            )
+            .async_call()
+            .call_and_exit();
+    }
+
+    /// The created eggs are kept for sale. Minting on demand is enabled afterwards, and counts
+    /// them against the max supply.
+    #[endpoint]
+    fn create_egg_nonce(
+        &self,
+        sale_id: u64,
+        initial_quantity: u64,
+        name: ManagedBuffer,
+        royalties: BigUint,
+        attributes: ManagedBuffer,
+        #[var_args] uris: MultiValueEncoded<ManagedBuffer>,
+    ) -> u64 {
+        self.require_admin();
+
+        require!(
+            !self.token_identifier(sale_id).is_empty(),
+            ERR_COLLECTION_NOT_ISSUED
+        );
+        require!(
+            self.token_nonce(sale_id).get() == 0,
+            ERR_EGG_NONCE_ALREADY_CREATED
+        );
+        require!(initial_quantity > 0, ERR_EGG_ZERO_QUANTITY);
+
+        let token = self.token_identifier(sale_id).get();
+        let amount = BigUint::from(initial_quantity);
+
+        let nonce = self.send().esdt_nft_create(
+            &token,
+            &amount,
+            &name,
+            &royalties,
+            &ManagedBuffer::new(),
+            &attributes,
+            &uris.to_vec(),
+        );
+
+        self.token_nonce(sale_id).set(nonce);
+
+        return nonce;
     }
 
     #[endpoint]
     #[payable("*")]
-    #[only_owner]
     fn fill_egg(
         &self,
         #[payment] _payment: BigUint,
         #[payment_token] token: TokenIdentifier,
         #[payment_nonce] nonce: u64,
+        sale_id: u64,
     ) {
-        self.blockchain().check_caller_is_owner();
+        self.require_admin();
+        self.require_sale_exists(sale_id);
 
         require!(
-            self.token_identifier().get() == token,
+            self.token_identifier(sale_id).get() == token,
             ERR_FILL_BAD_IDENTIFIER
         );
 
-        require!(self.token_nonce().get() == nonce, ERR_FILL_BAD_NONCE);
+        require!(self.token_nonce(sale_id).get() == nonce, ERR_FILL_BAD_NONCE);
+        require!(
+            !self.is_minting_on_demand(sale_id),
+            mint::ERR_FILL_WHILE_MINTING
+        );
+    }
+
+    /// The contract must hold the ESDTNFTAddQuantity role of the egg collection.
+    /// The eggs it already holds count against the max supply.
+    #[endpoint]
+    fn enable_mint_on_demand(&self, sale_id: u64, max_supply: u64) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
+        require!(
+            self.token_nonce(sale_id).get() != 0,
+            ERR_EGG_NONCE_NOT_CREATED
+        );
+
+        let token = self.token_identifier(sale_id).get();
+        self.require_mint_role(&token);
+
+        if !self.is_minting_on_demand(sale_id) {
+            let balance = self
+                .blockchain()
+                .get_sc_balance(&token, self.token_nonce(sale_id).get())
+                .to_u64();
+            require!(balance.is_some(), mint::ERR_MAX_SUPPLY_BELOW_MINTED);
+
+            self.minted_amount(sale_id)
+                .update(|minted| *minted += balance.unwrap());
+        }
+
+        self.set_max_supply(sale_id, max_supply);
     }
 
     #[view(getRemainingNft)]
-    fn get_remaining_nft(&self) -> BigUint {
-        return self
-            .blockchain()
-            .get_sc_balance(&self.token_identifier().get(), self.token_nonce().get());
+    fn get_remaining_nft(&self, sale_id: u64) -> BigUint {
+        let balance = self.blockchain().get_sc_balance(
+            &self.token_identifier(sale_id).get(),
+            self.token_nonce(sale_id).get(),
+        );
+
+        if self.is_minting_on_demand(sale_id) {
+            return balance + self.get_unminted_supply(sale_id);
+        }
+
+        return balance;
     }
 
+    /// The eggs of the team reserve and the ones waiting to be claimed can't be sold.
+    #[view(getAvailableEggs)]
+    fn get_available_eggs(&self, sale_id: u64) -> BigUint {
+        let remaining_nft = self.get_remaining_nft(sale_id);
+        let locked = self.get_locked_eggs(sale_id);
+
+        if remaining_nft > locked {
+            return remaining_nft - locked;
+        }
+
+        return BigUint::zero();
+    }
+
+    fn get_locked_eggs(&self, sale_id: u64) -> BigUint {
+        return BigUint::from(
+            self.get_remaining_reserve(sale_id) + self.total_pending_eggs(sale_id).get(),
+        );
+    }
+
+    fn send_eggs(&self, sale_id: u64, to: &ManagedAddress, amount: &BigUint) {
+        let token = self.token_identifier(sale_id).get();
+        let nonce = self.token_nonce(sale_id).get();
+
+        if self.is_minting_on_demand(sale_id) {
+            // the eggs held are sent before minting more
+            let balance = self.blockchain().get_sc_balance(&token, nonce);
+
+            if amount > &balance {
+                let to_mint = (amount - &balance).to_u64();
+                require!(to_mint.is_some(), mint::ERR_MAX_SUPPLY_REACHED);
+
+                self.mint_eggs(sale_id, &token, nonce, to_mint.unwrap());
+            }
+        }
+
+        self.send().direct(to, &token, nonce, amount, &[]);
+    }
+
+    /// A zero referrer can be passed to use a promo code without being referred.
     #[endpoint]
     #[payable("*")]
     fn buy(
@@ -140,102 +711,339 @@ pub trait PublicSaleMint: whitelist::WhitelistModule {
         #[payment] payment_amount: BigUint,
         #[payment_token] token: TokenIdentifier,
         #[payment_nonce] _nonce: u64,
+        sale_id: u64,
         to_buy: u64,
+        #[var_args] referrer: OptionalValue<ManagedAddress>,
+        #[var_args] promo_code: OptionalValue<ManagedBuffer>,
     ) {
         let caller = self.blockchain().get_caller();
 
-        if caller != self.blockchain().get_owner_address() {
-            require!(self.is_sale_over() == false, ERR_SALE_CLOSED);
-            require!(self.has_access(&caller) == true, ERR_SALE_NOT_OPEN);
+        self.require_not_paused();
+        self.require_sale_exists(sale_id);
+
+        if caller != self.get_admin() {
+            require!(self.is_sale_over(sale_id) == false, ERR_SALE_CLOSED);
+            require!(self.has_access(sale_id, &caller) == true, ERR_SALE_NOT_OPEN);
+            self.check_anti_bot(&caller, to_buy);
         }
         require!(token.is_egld(), ERR_BUY_NOT_EGLD);
-        require!(self.get_remaining_nft() > 0, ERR_SOLD_OUT);
+        require!(self.get_available_eggs(sale_id) >= to_buy, ERR_SOLD_OUT);
 
-        let already_bought = self.get_bought_amount(&caller);
+        let already_bought = self.get_bought_amount(sale_id, &caller);
+
+        require!(
+            already_bought + to_buy <= self.get_max_per_wallet(sale_id, &caller),
+            ERR_MAX_PER_WALLET_EXCEEDED
+        );
+
+        let referrer = referrer
+            .into_option()
+            .filter(|referrer| referrer != &ManagedAddress::zero());
+        let mut referee_discount = 0;
+        if let Some(referrer) = &referrer {
+            self.check_referrer(&caller, referrer);
+
+            referee_discount = self.referee_discount().get();
+        }
+
+        let promo_discount = match promo_code.into_option() {
+            Some(promo_code) => self.use_promo_code(&promo_code),
+            None => 0,
+        };
 
         require!(
             self.is_price_valid(
-                payment_amount,
+                payment_amount.clone(),
+                sale_id,
+                &caller,
                 already_bought,
-                self.get_price_list(&caller),
-                to_buy
-            ) == true,
+                to_buy,
+                &[referee_discount, promo_discount],
+            ),
             ERR_BAD_AMOUNT_SENT
         );
 
-        // send eggs to the caller
-        self.send().direct(
-            &caller,
-            &self.token_identifier().get(),
-            self.token_nonce().get(),
-            &BigUint::from(to_buy),
-            &[],
+        self.consume_allocation(sale_id, self.get_current_phase(sale_id), to_buy);
+
+        if self.is_claim_delayed(sale_id) {
+            self.record_pending_eggs(sale_id, &caller, to_buy);
+        } else {
+            // send eggs to the caller
+            self.send_eggs(sale_id, &caller, &BigUint::from(to_buy));
+        }
+
+        self.record_purchase(sale_id, &caller, to_buy, &payment_amount);
+
+        let mut revenue = payment_amount;
+        if let Some(referrer) = &referrer {
+            revenue -= self.record_referral(referrer, &revenue);
+        }
+
+        if self.forward_revenue().get() {
+            self.release_revenue(&revenue);
+        }
+    }
+
+    #[endpoint]
+    fn claim(&self, sale_id: u64) {
+        let caller = self.blockchain().get_caller();
+        let pending = self.take_pending_eggs(sale_id, &caller);
+
+        self.send_eggs(sale_id, &caller, &BigUint::from(pending));
+    }
+
+    /// Sells and delivers another nonce from now on, e.g. the revealed eggs.
+    /// The eggs left of the previous nonce are sent back to the admin.
+    /// The contract must already hold (or be able to mint) enough eggs of the new nonce
+    /// for the ones waiting to be claimed and the rest of the team reserve.
+    #[endpoint]
+    fn reveal(&self, sale_id: u64, nonce: u64) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
+        let token = self.token_identifier(sale_id).get();
+        let previous_nonce = self.token_nonce(sale_id).get();
+        let previous_balance = self.blockchain().get_sc_balance(&token, previous_nonce);
+
+        self.token_nonce(sale_id).set(nonce);
+
+        require!(
+            self.get_remaining_nft(sale_id) >= self.get_locked_eggs(sale_id),
+            ERR_REVEAL_NOT_COVERED
         );
 
-        self.already_bought()
-            .insert(caller, already_bought + to_buy);
+        if previous_nonce != nonce && previous_balance > 0 {
+            let admin = self.get_admin();
+            self.send()
+                .direct(&admin, &token, previous_nonce, &previous_balance, &[]);
+        }
     }
 
+    #[endpoint]
+    fn mint_reserved(&self, sale_id: u64, to: ManagedAddress, amount: u64) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
+        self.consume_reserve(sale_id, &to, amount);
+
+        self.send_eggs(sale_id, &to, &BigUint::from(amount));
+    }
+
+    #[endpoint]
+    fn airdrop(
+        &self,
+        sale_id: u64,
+        #[var_args] recipients: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>,
+    ) {
+        self.require_admin();
+        self.require_sale_exists(sale_id);
+
+        let mut available = self.get_available_eggs(sale_id);
+        let mut total = 0u64;
+
+        for recipient in recipients.into_iter() {
+            let (to, amount) = recipient.into_tuple();
+            let amount_big = BigUint::from(amount);
+
+            require!(amount > 0, airdrop::ERR_AIRDROP_ZERO_AMOUNT);
+            require!(
+                amount_big <= available,
+                airdrop::ERR_AIRDROP_NOT_ENOUGH_EGGS
+            );
+
+            self.send_eggs(sale_id, &to, &amount_big);
+
+            self.record_airdrop(sale_id, &to, amount);
+
+            available -= amount_big;
+            total += amount;
+        }
+
+        require!(total > 0, airdrop::ERR_AIRDROP_EMPTY);
+    }
+
+    /// The discounts, e.g. of a referral or a promo code, are applied in turn on the price
+    /// the address pays.
     fn is_price_valid(
         &self,
         payment_amount: BigUint,
+        sale_id: u64,
+        address: &ManagedAddress,
         already_bought: u64,
-        prices: VecMapper<BigUint>,
         to_buy: u64,
+        discounts: &[u64],
     ) -> bool {
+        match self.get_price_to_pay(sale_id, address, already_bought, to_buy) {
+            Some(mut price) => {
+                for discount in discounts.iter() {
+                    price = self.apply_discount(&price, *discount);
+                }
+
+                return price == payment_amount;
+            }
+            None => return false,
+        }
+    }
+
+    fn get_list_unit_price(
+        &self,
+        prices: &VecMapper<BigUint>,
+        already_bought: u64,
+        to_buy: u64,
+    ) -> Option<BigUint> {
         let price_index = (to_buy + already_bought) as usize;
         if price_index == 0 || price_index > prices.len() {
-            return false;
+            return None;
         }
-        let price = prices.get(price_index);
-        self.print().print_biguint(&price);
 
-        return &price * to_buy == payment_amount;
+        return Some(prices.get(price_index));
     }
 
-    fn is_sale_over(&self) -> bool {
+    /// Returns None if the price list of the address doesn't go that far, the eggs of an
+    /// allocation override past the list cost its last price.
+    fn get_price_to_pay(
+        &self,
+        sale_id: u64,
+        address: &ManagedAddress,
+        already_bought: u64,
+        to_buy: u64,
+    ) -> Option<BigUint> {
+        if let Some(price) = self.get_custom_price(sale_id, address) {
+            return Some(&price * to_buy);
+        }
+
+        let prices = self.get_price_list(sale_id, address);
+        let mut price = self.get_list_unit_price(&prices, already_bought, to_buy);
+        if price.is_none() && to_buy > 0 && self.allocation_overrides(sale_id).contains_key(address)
+        {
+            price = Some(prices.get(prices.len()));
+        }
+        let price = price?;
+        let discount = self.get_holder_discount(self.get_held_amount(sale_id, address));
+
+        return Some(self.apply_discount(&price, discount) * to_buy);
+    }
+
+    fn is_sale_over(&self, sale_id: u64) -> bool {
         let now = self.blockchain().get_block_timestamp();
-        let close = self.timestamp_sale_closed().get();
+        let close = self.timestamp_sale_closed(sale_id).get();
 
         return now >= close;
     }
 
-    fn get_price_list(&self, address: &ManagedAddress) -> VecMapper<BigUint> {
-        if self.check_contains_second(address) {
-            return self.reduced_price_per_egg();
+    /// The vesting starts once every sale is closed, until the first release pins it.
+    #[view(getVestingStart)]
+    fn get_vesting_start(&self) -> u64 {
+        if !self.vesting_start().is_empty() {
+            return self.vesting_start().get();
+        }
+
+        return self.get_last_sale_close();
+    }
+
+    fn get_last_sale_close(&self) -> u64 {
+        let mut last_close = 0u64;
+
+        for sale_id in 1..=self.last_sale_id().get() {
+            last_close = last_close.max(self.timestamp_sale_closed(sale_id).get());
+        }
+
+        return last_close;
+    }
+
+    #[view(getCurrentPhase)]
+    fn get_current_phase(&self, sale_id: u64) -> SalePhase {
+        let now = self.blockchain().get_block_timestamp();
+
+        if self.is_sale_over(sale_id) {
+            return SalePhase::Closed;
+        } else if now >= self.timestamp_public_sale(sale_id).get() {
+            return SalePhase::Public;
+        } else if self.is_second_whitelist_open(sale_id) {
+            return SalePhase::SecondWhitelist;
+        } else if self.is_first_whitelist_open(sale_id) {
+            return SalePhase::FirstWhitelist;
+        } else if self.is_holder_sale_open(sale_id) {
+            return SalePhase::Holder;
+        } else if now >= self.timestamp_first_whitelist(sale_id).get() {
+            return SalePhase::BetweenPhases;
+        }
+
+        return SalePhase::NotStarted;
+    }
+
+    fn get_price_list(&self, sale_id: u64, address: &ManagedAddress) -> VecMapper<BigUint> {
+        if self.get_buyer_tier(sale_id, address) == BuyerTier::SecondWhitelist {
+            return self.reduced_price_per_egg(sale_id);
         } else {
-            return self.price_per_egg();
+            return self.price_per_egg(sale_id);
         }
     }
 
+    #[view(getMaxPerWallet)]
+    fn get_max_per_wallet(&self, sale_id: u64, address: &ManagedAddress) -> u64 {
+        if let Some(allocation_override) = self.allocation_overrides(sale_id).get(address) {
+            return allocation_override.max_per_wallet;
+        }
+
+        return self
+            .max_per_wallet(sale_id, &self.get_buyer_tier(sale_id, address))
+            .get();
+    }
+
+    #[view(getPriceQuote)]
+    fn get_price_quote(&self, sale_id: u64, address: &ManagedAddress, to_buy: u64) -> BigUint {
+        let already_bought = self.get_bought_amount(sale_id, address);
+
+        require!(
+            to_buy > 0 && already_bought + to_buy <= self.get_max_per_wallet(sale_id, address),
+            ERR_MAX_PER_WALLET_EXCEEDED
+        );
+
+        let price = self.get_price_to_pay(sale_id, address, already_bought, to_buy);
+
+        require!(price.is_some(), ERR_MAX_PER_WALLET_EXCEEDED);
+
+        return price.unwrap();
+    }
+
+    #[view(getHolderPricePreview)]
+    fn get_holder_price_preview(&self, sale_id: u64, held_amount: u64, to_buy: u64) -> BigUint {
+        let price = self.get_list_unit_price(&self.price_per_egg(sale_id), 0, to_buy);
+
+        require!(price.is_some(), ERR_MAX_PER_WALLET_EXCEEDED);
+
+        let discount = self.get_holder_discount(held_amount);
+
+        return self.apply_discount(&price.unwrap(), discount) * to_buy;
+    }
+
     #[view(getBoughtAmount)]
-    fn get_bought_amount(&self, address: &ManagedAddress) -> u64 {
-        match self.already_bought().get(address) {
-            Some(amount) => amount,
+    fn get_bought_amount(&self, sale_id: u64, address: &ManagedAddress) -> u64 {
+        match self.buyers(sale_id).get(address) {
+            Some(record) => record.bought,
             None => 0,
         }
     }
 
     #[view(getAllBuyers)]
-    fn get_all_buyers(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+    fn get_all_buyers(&self, sale_id: u64) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
         let mut buyers = MultiValueEncoded::new();
 
-        for (address, balance) in self.already_bought().iter() {
-            let value = MultiValue2::from((address, balance));
+        for (address, record) in self.buyers(sale_id).iter() {
+            let value = MultiValue2::from((address, record.bought));
             buyers.push(value);
         }
 
         return buyers;
     }
 
-    #[only_owner]
     #[endpoint]
     fn claim_balance(&self) {
-        self.blockchain().check_caller_is_owner();
+        self.require_role(Role::Treasurer);
+        require!(!self.is_multisig_enabled(), multisig::ERR_MULTISIG_REQUIRED);
 
-        let balance = self
-            .blockchain()
-            .get_sc_balance(&TokenIdentifier::egld(), 0);
+        let balance = self.get_claimable_balance();
 
         // STEP 2 : require balance > 0
         require!(
@@ -243,34 +1051,112 @@ pub trait PublicSaleMint: whitelist::WhitelistModule {
             "There is nothing to claim. The balance is empty."
         );
 
-        // STEP 3 : send balance to owner
-        let owner = self.blockchain().get_owner_address();
-        self.send().direct_egld(&owner, &balance, &[]);
+        self.record_vesting_release(&balance);
+
+        // STEP 3 : send balance to the payees, or to the admin if there is none
+        self.release_revenue(&balance);
     }
 
-    #[only_owner]
     #[endpoint]
-    fn claim_eggs(&self) {
-        self.blockchain().check_caller_is_owner();
+    fn execute_withdrawal(&self, proposal_id: u64) {
+        let proposal = self.take_approved_proposal(proposal_id);
+
+        match proposal.asset {
+            WithdrawalAsset::Egld => {
+                require!(
+                    proposal.amount <= self.get_claimable_balance(),
+                    multisig::ERR_WITHDRAWAL_EXCEEDS_BALANCE
+                );
+
+                self.record_vesting_release(&proposal.amount);
+
+                self.send()
+                    .direct_egld(&proposal.destination, &proposal.amount, &[]);
+            }
+            WithdrawalAsset::Eggs => {
+                self.require_sale_exists(proposal.sale_id);
+                require!(
+                    proposal.amount <= self.get_available_eggs(proposal.sale_id),
+                    multisig::ERR_WITHDRAWAL_EXCEEDS_BALANCE
+                );
+
+                self.send_eggs(proposal.sale_id, &proposal.destination, &proposal.amount);
+            }
+        }
+    }
 
-        let balance = self
+    /// Only the vested part of the balance can be claimed when there is a vesting schedule.
+    fn get_claimable_balance(&self) -> BigUint {
+        if self.vesting_schedule().is_empty() {
+            return self.get_revenue_balance();
+        }
+
+        return self.get_releasable_amount();
+    }
+
+    /// The referral rewards not claimed yet are owed to the referrers.
+    fn get_revenue_balance(&self) -> BigUint {
+        return self
             .blockchain()
-            .get_sc_balance(&self.token_identifier().get(), self.token_nonce().get());
+            .get_sc_balance(&TokenIdentifier::egld(), 0)
+            - self.total_referral_rewards().get();
+    }
 
-        // STEP 2 : require balance > 0
+    #[view(getVestedAmount)]
+    fn get_vested_amount(&self) -> BigUint {
+        if self.vesting_schedule().is_empty() {
+            return BigUint::zero();
+        }
+
+        let total = self.get_revenue_balance() + self.vesting_released().get();
+
+        return self.compute_vested_amount(self.get_vesting_start(), &total);
+    }
+
+    fn record_vesting_release(&self, amount: &BigUint) {
+        if self.vesting_schedule().is_empty() {
+            return;
+        }
+
+        if self.vesting_start().is_empty() {
+            self.vesting_start().set(self.get_last_sale_close());
+        }
+
+        self.vesting_released()
+            .update(|released| *released += amount);
+    }
+
+    #[view(getReleasableAmount)]
+    fn get_releasable_amount(&self) -> BigUint {
+        return self.get_vested_amount() - self.vesting_released().get();
+    }
+
+    #[endpoint]
+    fn claim_eggs(&self, sale_id: u64) {
+        self.require_role(Role::Treasurer);
+        require!(!self.is_multisig_enabled(), multisig::ERR_MULTISIG_REQUIRED);
+        self.require_sale_exists(sale_id);
+
+        let token = self.token_identifier(sale_id).get();
+        let nonce = self.token_nonce(sale_id).get();
+        let balance = self.blockchain().get_sc_balance(&token, nonce);
+
+        // the eggs owed to the buyers and the team reserve stay in the contract
+        let available = self.get_available_eggs(sale_id);
+        let amount = if balance < available {
+            balance
+        } else {
+            available
+        };
+
+        // STEP 2 : require amount > 0
         require!(
-            balance > 0,
+            amount > 0,
             "There is nothing to claim. The balance is empty."
         );
 
-        // STEP 3 : send balance to owner
-        let owner = self.blockchain().get_owner_address();
-        self.send().direct(
-            &owner,
-            &self.token_identifier().get(),
-            self.token_nonce().get(),
-            &balance,
-            &[],
-        );
+        // STEP 3 : send amount to admin
+        let admin = self.get_admin();
+        self.send().direct(&admin, &token, nonce, &amount, &[]);
     }
 }
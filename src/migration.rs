@@ -0,0 +1,139 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+/// Bumped with every change of the storage layout, along with a step in `migrate_storage`.
+pub const STORAGE_VERSION: u32 = 1;
+
+/// The sale of a contract deployed before the sales were numbered becomes the sale 1.
+pub const UNNUMBERED_SALE_ID: u64 = 1;
+
+pub const ERR_STORAGE_VERSION_UNKNOWN: &str =
+    "The storage has been written by a more recent version of the contract";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct BuyerRecord<M: ManagedTypeApi> {
+    pub bought: u64,
+    /// In eGLD, zero for the eggs bought before the version 1 of the storage.
+    pub spent: BigUint<M>,
+    /// Zero for the eggs bought before the version 1 of the storage.
+    pub last_purchase: u64,
+}
+
+#[elrond_wasm::module]
+pub trait MigrationModule {
+    /// Zero for a contract deployed before the storage was versioned.
+    #[view(getStorageVersion)]
+    #[storage_mapper("storage_version")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("buyers")]
+    fn buyers(&self, sale_id: u64) -> MapMapper<ManagedAddress, BuyerRecord<Self::Api>>;
+
+    /// Layout of the version 0, only read to migrate it.
+    #[storage_mapper("already_bought")]
+    fn legacy_already_bought(&self, sale_id: u64) -> MapMapper<ManagedAddress, u64>;
+
+    // The layout of the contract deployed before the sales were numbered, only read to move
+    // it to the sale `UNNUMBERED_SALE_ID`.
+
+    #[storage_mapper("max_per_wallet")]
+    fn unnumbered_max_per_wallet(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("price_per_egg")]
+    fn unnumbered_price_per_egg(&self) -> VecMapper<BigUint>;
+
+    #[storage_mapper("reduced_price_per_egg")]
+    fn unnumbered_reduced_price_per_egg(&self) -> VecMapper<BigUint>;
+
+    #[storage_mapper("token_identifier")]
+    fn unnumbered_token_identifier(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[storage_mapper("token_nonce")]
+    fn unnumbered_token_nonce(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("already_bought")]
+    fn unnumbered_already_bought(&self) -> MapMapper<ManagedAddress, u64>;
+
+    #[storage_mapper("timestamp_first_whitelist")]
+    fn unnumbered_timestamp_first_whitelist(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timestamp_second_whitelist")]
+    fn unnumbered_timestamp_second_whitelist(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timestamp_public_sale")]
+    fn unnumbered_timestamp_public_sale(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("timestamp_sale_closed")]
+    fn unnumbered_timestamp_sale_closed(&self) -> SingleValueMapper<u64>;
+
+    /// Can't be iterated, the addresses are moved by `migrate_whitelisted`.
+    #[storage_mapper("first_whitelist_mapper")]
+    fn unnumbered_first_whitelist(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    #[storage_mapper("second_whitelist_mapper")]
+    fn unnumbered_second_whitelist(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+
+    fn has_unnumbered_sale(&self) -> bool {
+        return !self.unnumbered_timestamp_sale_closed().is_empty();
+    }
+
+    #[view(getBuyerRecord)]
+    fn get_buyer_record(
+        &self,
+        sale_id: u64,
+        address: &ManagedAddress,
+    ) -> Option<BuyerRecord<Self::Api>> {
+        return self.buyers(sale_id).get(address);
+    }
+
+    fn record_purchase(&self, sale_id: u64, address: &ManagedAddress, amount: u64, paid: &BigUint) {
+        let mut record = self.buyers(sale_id).get(address).unwrap_or(BuyerRecord {
+            bought: 0,
+            spent: BigUint::zero(),
+            last_purchase: 0,
+        });
+
+        record.bought += amount;
+        record.spent += paid;
+        record.last_purchase = self.blockchain().get_block_timestamp();
+
+        self.buyers(sale_id).insert(address.clone(), record);
+    }
+
+    /// Runs every step between the stored version and the current one, so it does nothing
+    /// on an up to date storage.
+    fn migrate_storage(&self, last_sale_id: u64) {
+        let version = self.storage_version().get();
+        require!(version <= STORAGE_VERSION, ERR_STORAGE_VERSION_UNKNOWN);
+
+        if version < 1 {
+            for sale_id in 1..=last_sale_id {
+                self.migrate_buyer_records(sale_id);
+            }
+        }
+
+        self.storage_version().set(STORAGE_VERSION);
+
+        if version < STORAGE_VERSION {
+            self.storage_migrated_event(version, STORAGE_VERSION);
+        }
+    }
+
+    fn migrate_buyer_records(&self, sale_id: u64) {
+        for (address, bought) in self.legacy_already_bought(sale_id).iter() {
+            self.buyers(sale_id).insert(
+                address,
+                BuyerRecord {
+                    bought,
+                    spent: BigUint::zero(),
+                    last_purchase: 0,
+                },
+            );
+        }
+
+        self.legacy_already_bought(sale_id).clear();
+    }
+
+    #[event("storage_migrated")]
+    fn storage_migrated_event(&self, #[indexed] from_version: u32, #[indexed] to_version: u32);
+}
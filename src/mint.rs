@@ -0,0 +1,61 @@
+elrond_wasm::imports!();
+
+pub const ERR_MINT_MISSING_ROLE: &str = "The contract must have the ESDTNFTAddQuantity role";
+pub const ERR_MAX_SUPPLY_BELOW_MINTED: &str = "The max supply can't be lower than the minted eggs";
+pub const ERR_MAX_SUPPLY_REACHED: &str = "Not enough eggs left to mint";
+pub const ERR_FILL_WHILE_MINTING: &str = "The eggs are minted on demand, they can't be filled";
+
+#[elrond_wasm::module]
+pub trait MintModule: crate::admin::AdminModule {
+    /// When set, the eggs are minted when delivered instead of being sent with `fill_egg`.
+    #[view(getMaxSupply)]
+    #[storage_mapper("max_supply")]
+    fn max_supply(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getMintedAmount)]
+    #[storage_mapper("minted_amount")]
+    fn minted_amount(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    fn is_minting_on_demand(&self, sale_id: u64) -> bool {
+        return !self.max_supply(sale_id).is_empty();
+    }
+
+    fn require_mint_role(&self, token: &TokenIdentifier) {
+        let roles = self.blockchain().get_esdt_local_roles(token);
+
+        require!(
+            roles.has_role(&EsdtLocalRole::NftAddQuantity),
+            ERR_MINT_MISSING_ROLE
+        );
+    }
+
+    fn set_max_supply(&self, sale_id: u64, max_supply: u64) {
+        require!(
+            max_supply >= self.minted_amount(sale_id).get(),
+            ERR_MAX_SUPPLY_BELOW_MINTED
+        );
+
+        self.max_supply(sale_id).set(max_supply);
+    }
+
+    fn get_unminted_supply(&self, sale_id: u64) -> u64 {
+        return self.max_supply(sale_id).get() - self.minted_amount(sale_id).get();
+    }
+
+    fn record_minted(&self, sale_id: u64, amount: u64) {
+        require!(
+            amount <= self.get_unminted_supply(sale_id),
+            ERR_MAX_SUPPLY_REACHED
+        );
+
+        self.minted_amount(sale_id)
+            .update(|minted| *minted += amount);
+    }
+
+    fn mint_eggs(&self, sale_id: u64, token: &TokenIdentifier, nonce: u64, amount: u64) {
+        self.record_minted(sale_id, amount);
+
+        self.send()
+            .esdt_local_mint(token, nonce, &BigUint::from(amount));
+    }
+}
@@ -0,0 +1,216 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+pub const ERR_MULTISIG_REQUIRED: &str = "Withdrawals must be approved by the board";
+pub const ERR_MULTISIG_DISABLED: &str = "There is no board to approve withdrawals";
+pub const ERR_MULTISIG_BAD_QUORUM: &str = "The quorum must be between 1 and the board size";
+pub const ERR_NOT_BOARD_MEMBER: &str = "Endpoint can only be called by a board member";
+pub const ERR_PROPOSAL_NOT_FOUND: &str = "There is no such withdrawal proposal";
+pub const ERR_PROPOSAL_EXPIRED: &str = "The withdrawal proposal has expired";
+pub const ERR_PROPOSAL_ALREADY_SIGNED: &str = "The withdrawal proposal is already signed";
+pub const ERR_PROPOSAL_QUORUM_NOT_REACHED: &str = "The withdrawal proposal lacks signatures";
+pub const ERR_PROPOSAL_ZERO_AMOUNT: &str = "The amount to withdraw must be positive";
+pub const ERR_WITHDRAWAL_EXCEEDS_BALANCE: &str = "The balance can't cover this withdrawal";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum WithdrawalAsset {
+    Egld,
+    Eggs,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct WithdrawalProposal<M: ManagedTypeApi> {
+    /// Only used to withdraw the eggs of this sale.
+    pub sale_id: u64,
+    pub asset: WithdrawalAsset,
+    pub amount: BigUint<M>,
+    pub destination: ManagedAddress<M>,
+    pub proposer: ManagedAddress<M>,
+    pub expiration: u64,
+}
+
+#[elrond_wasm::module]
+pub trait MultisigModule: crate::admin::AdminModule {
+    #[storage_mapper("board_members")]
+    fn board_members(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Withdrawals need the approval of the board when the quorum is set.
+    #[view]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<usize>;
+
+    #[view]
+    #[storage_mapper("proposal_duration")]
+    fn proposal_duration(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("last_proposal_id")]
+    fn last_proposal_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposals")]
+    fn proposals(&self) -> MapMapper<u64, WithdrawalProposal<Self::Api>>;
+
+    #[storage_mapper("proposal_signers")]
+    fn proposal_signers(&self, proposal_id: u64) -> SetMapper<ManagedAddress>;
+
+    /// An empty board disables the approval of withdrawals.
+    /// The open proposals are dropped, they were signed by the previous board.
+    #[endpoint]
+    fn set_board(
+        &self,
+        quorum: usize,
+        proposal_duration: u64,
+        #[var_args] members: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.require_admin();
+
+        for proposal_id in self.proposals().keys() {
+            self.proposal_signers(proposal_id).clear();
+        }
+        self.proposals().clear();
+
+        self.board_members().clear();
+        for member in members.into_iter() {
+            self.board_members().insert(member);
+        }
+
+        let board_size = self.board_members().len();
+        require!(
+            (board_size == 0 && quorum == 0) || (quorum > 0 && quorum <= board_size),
+            ERR_MULTISIG_BAD_QUORUM
+        );
+
+        self.quorum().set(quorum);
+        self.proposal_duration().set(proposal_duration);
+    }
+
+    #[endpoint]
+    fn propose_withdrawal(
+        &self,
+        sale_id: u64,
+        asset: WithdrawalAsset,
+        amount: BigUint,
+        destination: ManagedAddress,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+
+        require!(amount > 0, ERR_PROPOSAL_ZERO_AMOUNT);
+
+        let proposal_id = self.last_proposal_id().get() + 1;
+        self.last_proposal_id().set(proposal_id);
+
+        let expiration = self.blockchain().get_block_timestamp() + self.proposal_duration().get();
+
+        self.proposals().insert(
+            proposal_id,
+            WithdrawalProposal {
+                sale_id,
+                asset,
+                amount,
+                destination,
+                proposer: caller.clone(),
+                expiration,
+            },
+        );
+
+        self.proposal_signers(proposal_id).insert(caller);
+
+        return proposal_id;
+    }
+
+    #[endpoint]
+    fn sign_withdrawal(&self, proposal_id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_board_member(&caller);
+
+        self.get_active_proposal(proposal_id);
+
+        require!(
+            self.proposal_signers(proposal_id).insert(caller),
+            ERR_PROPOSAL_ALREADY_SIGNED
+        );
+    }
+
+    #[view(getProposals)]
+    fn get_proposals(
+        &self,
+    ) -> MultiValueEncoded<MultiValue3<u64, WithdrawalProposal<Self::Api>, usize>> {
+        let mut proposals = MultiValueEncoded::new();
+
+        for (proposal_id, proposal) in self.proposals().iter() {
+            let signatures = self.get_signatures_count(proposal_id);
+            proposals.push(MultiValue3::from((proposal_id, proposal, signatures)));
+        }
+
+        return proposals;
+    }
+
+    #[view(getProposalSigners)]
+    fn get_proposal_signers(&self, proposal_id: u64) -> MultiValueEncoded<ManagedAddress> {
+        let mut signers = MultiValueEncoded::new();
+
+        for signer in self.proposal_signers(proposal_id).iter() {
+            signers.push(signer);
+        }
+
+        return signers;
+    }
+
+    #[view(getBoardMembers)]
+    fn get_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut members = MultiValueEncoded::new();
+
+        for member in self.board_members().iter() {
+            members.push(member);
+        }
+
+        return members;
+    }
+
+    fn is_multisig_enabled(&self) -> bool {
+        return self.quorum().get() > 0;
+    }
+
+    fn require_board_member(&self, address: &ManagedAddress) {
+        require!(self.board_members().contains(address), ERR_NOT_BOARD_MEMBER);
+    }
+
+    /// The signers removed from the board no longer count.
+    fn get_signatures_count(&self, proposal_id: u64) -> usize {
+        return self
+            .proposal_signers(proposal_id)
+            .iter()
+            .filter(|signer| self.board_members().contains(signer))
+            .count();
+    }
+
+    fn get_active_proposal(&self, proposal_id: u64) -> WithdrawalProposal<Self::Api> {
+        let proposal = self.proposals().get(&proposal_id);
+        require!(proposal.is_some(), ERR_PROPOSAL_NOT_FOUND);
+
+        let proposal = proposal.unwrap();
+
+        require!(
+            self.blockchain().get_block_timestamp() < proposal.expiration,
+            ERR_PROPOSAL_EXPIRED
+        );
+
+        return proposal;
+    }
+
+    /// Removes an approved proposal so it can be executed once.
+    fn take_approved_proposal(&self, proposal_id: u64) -> WithdrawalProposal<Self::Api> {
+        require!(self.is_multisig_enabled(), ERR_MULTISIG_DISABLED);
+
+        let proposal = self.get_active_proposal(proposal_id);
+
+        require!(
+            self.get_signatures_count(proposal_id) >= self.quorum().get(),
+            ERR_PROPOSAL_QUORUM_NOT_REACHED
+        );
+
+        self.proposals().remove(&proposal_id);
+
+        return proposal;
+    }
+}
@@ -0,0 +1,95 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+pub const ERR_OVERRIDE_MAX_PER_WALLET_ZERO: &str = "The overridden max per wallet must be positive";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct AllocationOverride<M: ManagedTypeApi> {
+    pub max_per_wallet: u64,
+    pub price_per_egg: Option<BigUint<M>>,
+}
+
+#[elrond_wasm::module]
+pub trait OverridesModule: crate::admin::AdminModule {
+    #[storage_mapper("allocation_overrides")]
+    fn allocation_overrides(
+        &self,
+        sale_id: u64,
+    ) -> MapMapper<ManagedAddress, AllocationOverride<Self::Api>>;
+
+    /// Without a custom price, the address still pays the price list of its tier,
+    /// and the last price of that list for the eggs past its end.
+    #[endpoint]
+    fn set_allocation_override(
+        &self,
+        sale_id: u64,
+        address: ManagedAddress,
+        max_per_wallet: u64,
+        #[var_args] price_per_egg: OptionalValue<BigUint>,
+    ) {
+        self.require_admin();
+
+        require!(max_per_wallet > 0, ERR_OVERRIDE_MAX_PER_WALLET_ZERO);
+
+        let allocation_override = AllocationOverride {
+            max_per_wallet,
+            price_per_egg: price_per_egg.into_option(),
+        };
+
+        self.allocation_overrides(sale_id)
+            .insert(address.clone(), allocation_override.clone());
+
+        self.allocation_override_event(sale_id, &address, &Some(allocation_override));
+    }
+
+    #[endpoint]
+    fn remove_allocation_override(&self, sale_id: u64, address: ManagedAddress) {
+        self.require_admin();
+
+        if self
+            .allocation_overrides(sale_id)
+            .remove(&address)
+            .is_some()
+        {
+            self.allocation_override_event(sale_id, &address, &None);
+        }
+    }
+
+    #[view(getAllocationOverride)]
+    fn get_allocation_override(
+        &self,
+        sale_id: u64,
+        address: &ManagedAddress,
+    ) -> Option<AllocationOverride<Self::Api>> {
+        return self.allocation_overrides(sale_id).get(address);
+    }
+
+    #[view(getAllocationOverrides)]
+    fn get_allocation_overrides(
+        &self,
+        sale_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, AllocationOverride<Self::Api>>> {
+        let mut overrides = MultiValueEncoded::new();
+
+        for (address, allocation_override) in self.allocation_overrides(sale_id).iter() {
+            overrides.push(MultiValue2::from((address, allocation_override)));
+        }
+
+        return overrides;
+    }
+
+    fn get_custom_price(&self, sale_id: u64, address: &ManagedAddress) -> Option<BigUint> {
+        return self
+            .allocation_overrides(sale_id)
+            .get(address)
+            .and_then(|allocation_override| allocation_override.price_per_egg);
+    }
+
+    #[event("allocation_override")]
+    fn allocation_override_event(
+        &self,
+        #[indexed] sale_id: u64,
+        #[indexed] address: &ManagedAddress,
+        allocation_override: &Option<AllocationOverride<Self::Api>>,
+    );
+}
@@ -0,0 +1,30 @@
+elrond_wasm::imports!();
+
+use crate::roles::Role;
+
+pub const ERR_SALE_PAUSED: &str = "Sorry, the sale is paused.";
+
+#[elrond_wasm::module]
+pub trait PauseModule: crate::roles::RolesModule {
+    #[view]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[endpoint]
+    fn pause(&self) {
+        self.require_role(Role::Pauser);
+
+        self.paused().set(true);
+    }
+
+    #[endpoint]
+    fn unpause(&self) {
+        self.require_role(Role::Pauser);
+
+        self.paused().set(false);
+    }
+
+    fn require_not_paused(&self) {
+        require!(self.paused().get() == false, ERR_SALE_PAUSED);
+    }
+}
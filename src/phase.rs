@@ -0,0 +1,28 @@
+elrond_wasm::derive_imports!();
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum SalePhase {
+    NotStarted,
+    FirstWhitelist,
+    SecondWhitelist,
+    Public,
+    Closed,
+    /// A whitelist has ended and the next phase hasn't started yet.
+    BetweenPhases,
+    /// Only the verified holders can buy, no whitelist is open.
+    Holder,
+}
+
+impl SalePhase {
+    /// In rollover order, the holder sale usually opens before the whitelists.
+    pub const SELLING: [SalePhase; 4] = [
+        SalePhase::Holder,
+        SalePhase::FirstWhitelist,
+        SalePhase::SecondWhitelist,
+        SalePhase::Public,
+    ];
+
+    pub fn is_selling(&self) -> bool {
+        SalePhase::SELLING.contains(self)
+    }
+}
@@ -0,0 +1,109 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+use crate::revenue::TOTAL_SHARES;
+
+pub const ERR_PROMO_CODE_BAD_HASH: &str = "The promo code must be registered by its sha256 hash";
+pub const ERR_PROMO_CODE_BAD_DISCOUNT: &str = "The discount must be between 1 and 10000";
+pub const ERR_PROMO_CODE_NO_USAGE: &str = "The usage limit must be positive";
+pub const ERR_PROMO_CODE_NOT_FOUND: &str = "This promo code doesn't exist";
+pub const ERR_PROMO_CODE_EXPIRED: &str = "This promo code has expired";
+pub const ERR_PROMO_CODE_USED_UP: &str = "This promo code has reached its usage limit";
+
+const HASH_LENGTH: usize = 32;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct PromoCode {
+    /// In basis points.
+    pub discount: u64,
+    pub usage_limit: u64,
+    pub expiration: u64,
+}
+
+#[elrond_wasm::module]
+pub trait PromoModule: crate::admin::AdminModule {
+    /// The codes are stored by their sha256 hash, so they can't be read on-chain before being used.
+    #[storage_mapper("promo_codes")]
+    fn promo_codes(&self) -> MapMapper<ManagedBuffer, PromoCode>;
+
+    #[storage_mapper("promo_code_usage")]
+    fn promo_code_usage(&self) -> MapMapper<ManagedBuffer, u64>;
+
+    #[endpoint]
+    fn register_promo_code(
+        &self,
+        code_hash: ManagedBuffer,
+        discount: u64,
+        usage_limit: u64,
+        expiration: u64,
+    ) {
+        self.require_admin();
+
+        require!(code_hash.len() == HASH_LENGTH, ERR_PROMO_CODE_BAD_HASH);
+        require!(
+            discount > 0 && discount <= TOTAL_SHARES,
+            ERR_PROMO_CODE_BAD_DISCOUNT
+        );
+        require!(usage_limit > 0, ERR_PROMO_CODE_NO_USAGE);
+
+        self.promo_codes().insert(
+            code_hash,
+            PromoCode {
+                discount,
+                usage_limit,
+                expiration,
+            },
+        );
+    }
+
+    /// The usage counter is kept, so registering the code again doesn't reset it.
+    #[endpoint]
+    fn remove_promo_code(&self, code_hash: ManagedBuffer) {
+        self.require_admin();
+
+        self.promo_codes().remove(&code_hash);
+    }
+
+    #[view(getPromoCode)]
+    fn get_promo_code(&self, code_hash: &ManagedBuffer) -> Option<PromoCode> {
+        return self.promo_codes().get(code_hash);
+    }
+
+    #[view(getPromoCodeUsage)]
+    fn get_promo_code_usage(&self, code_hash: &ManagedBuffer) -> u64 {
+        return self.promo_code_usage().get(code_hash).unwrap_or_default();
+    }
+
+    #[view(getPromoCodes)]
+    fn get_promo_codes(&self) -> MultiValueEncoded<MultiValue3<ManagedBuffer, PromoCode, u64>> {
+        let mut codes = MultiValueEncoded::new();
+
+        for (code_hash, code) in self.promo_codes().iter() {
+            let usage = self.get_promo_code_usage(&code_hash);
+            codes.push(MultiValue3::from((code_hash, code, usage)));
+        }
+
+        return codes;
+    }
+
+    /// Counts one usage of the code and returns its discount.
+    fn use_promo_code(&self, code: &ManagedBuffer) -> u64 {
+        let code_hash = self.crypto().sha256(code).as_managed_buffer().clone();
+        let promo_code = self.promo_codes().get(&code_hash);
+
+        require!(promo_code.is_some(), ERR_PROMO_CODE_NOT_FOUND);
+
+        let promo_code = promo_code.unwrap();
+        let usage = self.get_promo_code_usage(&code_hash);
+
+        require!(
+            self.blockchain().get_block_timestamp() < promo_code.expiration,
+            ERR_PROMO_CODE_EXPIRED
+        );
+        require!(usage < promo_code.usage_limit, ERR_PROMO_CODE_USED_UP);
+
+        self.promo_code_usage().insert(code_hash, usage + 1);
+
+        return promo_code.discount;
+    }
+}
@@ -0,0 +1,118 @@
+elrond_wasm::imports!();
+
+use crate::revenue::TOTAL_SHARES;
+
+pub const ERR_SELF_REFERRAL: &str = "You can't refer yourself";
+pub const ERR_REFERRAL_TOO_HIGH: &str = "The referral shares can't be greater than 10000";
+pub const ERR_NO_REFERRAL_REWARDS: &str = "There is no referral reward to claim";
+
+#[elrond_wasm::module]
+pub trait ReferralModule: crate::admin::AdminModule {
+    /// In basis points, the part of the payment credited to the referrer.
+    #[view]
+    #[storage_mapper("referral_reward")]
+    fn referral_reward(&self) -> SingleValueMapper<u64>;
+
+    /// In basis points, the discount of the buyers coming with a referrer.
+    #[view]
+    #[storage_mapper("referee_discount")]
+    fn referee_discount(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("referral_rewards")]
+    fn referral_rewards(&self) -> MapMapper<ManagedAddress, BigUint>;
+
+    #[storage_mapper("referral_earnings")]
+    fn referral_earnings(&self) -> MapMapper<ManagedAddress, BigUint>;
+
+    #[storage_mapper("referral_count")]
+    fn referral_count(&self) -> MapMapper<ManagedAddress, u64>;
+
+    /// The rewards not claimed yet, which are not part of the revenue.
+    #[view(getTotalReferralRewards)]
+    #[storage_mapper("total_referral_rewards")]
+    fn total_referral_rewards(&self) -> SingleValueMapper<BigUint>;
+
+    #[endpoint]
+    fn set_referral_program(&self, referral_reward: u64, referee_discount: u64) {
+        self.require_admin();
+
+        require!(
+            referral_reward + referee_discount <= TOTAL_SHARES,
+            ERR_REFERRAL_TOO_HIGH
+        );
+
+        self.referral_reward().set(referral_reward);
+        self.referee_discount().set(referee_discount);
+    }
+
+    #[endpoint]
+    fn claim_referral_rewards(&self) {
+        let caller = self.blockchain().get_caller();
+        let rewards = self.get_referral_rewards(&caller);
+
+        require!(rewards > 0, ERR_NO_REFERRAL_REWARDS);
+
+        self.referral_rewards().remove(&caller);
+        self.total_referral_rewards()
+            .update(|total| *total -= &rewards);
+
+        self.send().direct_egld(&caller, &rewards, &[]);
+    }
+
+    #[view(getReferralRewards)]
+    fn get_referral_rewards(&self, referrer: &ManagedAddress) -> BigUint {
+        return self.referral_rewards().get(referrer).unwrap_or_default();
+    }
+
+    #[view(getReferralEarnings)]
+    fn get_referral_earnings(&self, referrer: &ManagedAddress) -> BigUint {
+        return self.referral_earnings().get(referrer).unwrap_or_default();
+    }
+
+    /// The number of purchases made with this referrer.
+    #[view(getReferralCount)]
+    fn get_referral_count(&self, referrer: &ManagedAddress) -> u64 {
+        return self.referral_count().get(referrer).unwrap_or_default();
+    }
+
+    #[view(getAllReferrers)]
+    fn get_all_referrers(&self) -> MultiValueEncoded<MultiValue3<ManagedAddress, u64, BigUint>> {
+        let mut referrers = MultiValueEncoded::new();
+
+        for (referrer, count) in self.referral_count().iter() {
+            let earnings = self.get_referral_earnings(&referrer);
+            referrers.push(MultiValue3::from((referrer, count, earnings)));
+        }
+
+        return referrers;
+    }
+
+    fn check_referrer(&self, buyer: &ManagedAddress, referrer: &ManagedAddress) {
+        require!(buyer != referrer, ERR_SELF_REFERRAL);
+    }
+
+    /// Credits the referrer and returns its reward, which must be kept out of the revenue.
+    fn record_referral(&self, referrer: &ManagedAddress, payment_amount: &BigUint) -> BigUint {
+        let reward = payment_amount * self.referral_reward().get() / TOTAL_SHARES;
+
+        self.referral_rewards().insert(
+            referrer.clone(),
+            self.get_referral_rewards(referrer) + &reward,
+        );
+        self.referral_earnings().insert(
+            referrer.clone(),
+            self.get_referral_earnings(referrer) + &reward,
+        );
+        self.referral_count()
+            .insert(referrer.clone(), self.get_referral_count(referrer) + 1);
+        self.total_referral_rewards()
+            .update(|total| *total += &reward);
+
+        self.referral_event(referrer, &reward);
+
+        return reward;
+    }
+
+    #[event("referral")]
+    fn referral_event(&self, #[indexed] referrer: &ManagedAddress, reward: &BigUint);
+}
@@ -0,0 +1,52 @@
+elrond_wasm::imports!();
+
+pub const ERR_RESERVE_EXCEEDED: &str = "The team reserve can't cover this amount";
+pub const ERR_RESERVE_ZERO_AMOUNT: &str = "The amount to mint must be positive";
+
+#[elrond_wasm::module]
+pub trait ReserveModule {
+    #[view]
+    #[storage_mapper("reserved_supply")]
+    fn reserved_supply(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("reserved_minted")]
+    fn reserved_minted(&self, sale_id: u64) -> MapMapper<ManagedAddress, u64>;
+
+    #[view(getReservedMintedAmount)]
+    #[storage_mapper("reserved_minted_amount")]
+    fn reserved_minted_amount(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getRemainingReserve)]
+    fn get_remaining_reserve(&self, sale_id: u64) -> u64 {
+        return self.reserved_supply(sale_id).get() - self.reserved_minted_amount(sale_id).get();
+    }
+
+    #[view(getAllReservedMints)]
+    fn get_all_reserved_mints(
+        &self,
+        sale_id: u64,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut mints = MultiValueEncoded::new();
+
+        for (address, amount) in self.reserved_minted(sale_id).iter() {
+            mints.push(MultiValue2::from((address, amount)));
+        }
+
+        return mints;
+    }
+
+    fn consume_reserve(&self, sale_id: u64, to: &ManagedAddress, amount: u64) {
+        require!(amount > 0, ERR_RESERVE_ZERO_AMOUNT);
+        require!(
+            amount <= self.get_remaining_reserve(sale_id),
+            ERR_RESERVE_EXCEEDED
+        );
+
+        self.reserved_minted_amount(sale_id)
+            .update(|minted| *minted += amount);
+
+        let already_minted = self.reserved_minted(sale_id).get(to).unwrap_or_default();
+        self.reserved_minted(sale_id)
+            .insert(to.clone(), already_minted + amount);
+    }
+}
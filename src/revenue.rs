@@ -0,0 +1,175 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+pub const TOTAL_SHARES: u64 = 10_000;
+
+pub const ERR_PAYEES_SHARES_SUM: &str = "The shares of the payees must sum to 10000";
+pub const ERR_PAYEE_SHARE_ZERO: &str = "The share of a payee must be positive";
+pub const ERR_PAYEE_DUPLICATE: &str = "A payee can only be set once";
+pub const ERR_VESTING_ALREADY_SET: &str = "The vesting schedule can't be changed once set";
+pub const ERR_VESTING_INITIAL_UNLOCK_TOO_HIGH: &str =
+    "The initial unlock can't be greater than 10000";
+pub const ERR_VESTING_WHILE_FORWARDING: &str =
+    "The revenue can't be both vested and forwarded on each purchase";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone)]
+pub struct VestingSchedule {
+    /// In basis points, unlocked when the sale closes.
+    pub initial_unlock: u64,
+    /// The remainder is released linearly over this duration, in seconds.
+    pub duration: u64,
+}
+
+#[elrond_wasm::module]
+pub trait RevenueModule: crate::admin::AdminModule {
+    /// Shares are in basis points. When empty, the revenue goes to the admin.
+    #[storage_mapper("payee_shares")]
+    fn payee_shares(&self) -> MapMapper<ManagedAddress, u64>;
+
+    /// When set, each purchase is sent right away instead of waiting for claim_balance.
+    #[view]
+    #[storage_mapper("forward_revenue")]
+    fn forward_revenue(&self) -> SingleValueMapper<bool>;
+
+    #[view(getVestingSchedule)]
+    #[storage_mapper("vesting_schedule")]
+    fn vesting_schedule(&self) -> SingleValueMapper<VestingSchedule>;
+
+    /// Pinned on the first release, so that a sale created afterwards can't move it.
+    #[storage_mapper("vesting_start")]
+    fn vesting_start(&self) -> SingleValueMapper<u64>;
+
+    #[view(getReleasedAmount)]
+    #[storage_mapper("vesting_released")]
+    fn vesting_released(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("payee_released")]
+    fn payee_released(&self) -> MapMapper<ManagedAddress, BigUint>;
+
+    #[endpoint]
+    fn set_payees(&self, #[var_args] payees: MultiValueEncoded<MultiValue2<ManagedAddress, u64>>) {
+        self.require_admin();
+
+        self.payee_shares().clear();
+
+        let mut total_shares = 0u64;
+        for payee in payees.into_iter() {
+            let (address, share) = payee.into_tuple();
+
+            require!(share > 0, ERR_PAYEE_SHARE_ZERO);
+            require!(
+                self.payee_shares().insert(address, share).is_none(),
+                ERR_PAYEE_DUPLICATE
+            );
+
+            total_shares += share;
+        }
+
+        require!(
+            total_shares == 0 || total_shares == TOTAL_SHARES,
+            ERR_PAYEES_SHARES_SUM
+        );
+    }
+
+    #[endpoint]
+    fn set_forward_revenue(&self, forward_revenue: bool) {
+        self.require_admin();
+
+        require!(
+            !forward_revenue || self.vesting_schedule().is_empty(),
+            ERR_VESTING_WHILE_FORWARDING
+        );
+
+        self.forward_revenue().set(forward_revenue);
+    }
+
+    /// Once set, claim_balance only releases the vested part of the revenue.
+    #[endpoint]
+    fn set_vesting_schedule(&self, initial_unlock: u64, duration: u64) {
+        self.require_admin();
+
+        require!(self.vesting_schedule().is_empty(), ERR_VESTING_ALREADY_SET);
+        require!(
+            initial_unlock <= TOTAL_SHARES,
+            ERR_VESTING_INITIAL_UNLOCK_TOO_HIGH
+        );
+        require!(
+            self.forward_revenue().get() == false,
+            ERR_VESTING_WHILE_FORWARDING
+        );
+
+        self.vesting_schedule().set(&VestingSchedule {
+            initial_unlock,
+            duration,
+        });
+    }
+
+    #[view(getPayees)]
+    fn get_payees(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, u64>> {
+        let mut payees = MultiValueEncoded::new();
+
+        for (address, share) in self.payee_shares().iter() {
+            payees.push(MultiValue2::from((address, share)));
+        }
+
+        return payees;
+    }
+
+    #[view(getReleased)]
+    fn get_released(&self, address: &ManagedAddress) -> BigUint {
+        return self.payee_released().get(address).unwrap_or_default();
+    }
+
+    fn compute_vested_amount(&self, vesting_start: u64, total: &BigUint) -> BigUint {
+        let now = self.blockchain().get_block_timestamp();
+        if now < vesting_start {
+            return BigUint::zero();
+        }
+
+        let schedule = self.vesting_schedule().get();
+        let elapsed = now - vesting_start;
+        if elapsed >= schedule.duration {
+            return total.clone();
+        }
+
+        let initial = total * schedule.initial_unlock / TOTAL_SHARES;
+        let linear = (total - &initial) * elapsed / schedule.duration;
+
+        return initial + linear;
+    }
+
+    fn release_revenue(&self, amount: &BigUint) {
+        if self.payee_shares().is_empty() {
+            let admin = self.get_admin();
+            self.send().direct_egld(&admin, amount, &[]);
+        } else {
+            self.distribute_revenue(amount);
+        }
+    }
+
+    /// Sends the amount to the payees, the rounding remainder goes to the last one.
+    fn distribute_revenue(&self, amount: &BigUint) {
+        let payees_count = self.payee_shares().len();
+        let mut distributed = BigUint::zero();
+
+        for (index, (address, share)) in self.payee_shares().iter().enumerate() {
+            let payee_amount = if index + 1 == payees_count {
+                amount - &distributed
+            } else {
+                amount * share / TOTAL_SHARES
+            };
+
+            if payee_amount == 0 {
+                continue;
+            }
+
+            self.send().direct_egld(&address, &payee_amount, &[]);
+
+            let released = self.get_released(&address);
+            self.payee_released()
+                .insert(address, released + &payee_amount);
+
+            distributed += payee_amount;
+        }
+    }
+}
@@ -0,0 +1,78 @@
+elrond_wasm::imports!();
+elrond_wasm::derive_imports!();
+
+pub const ERR_MISSING_ROLE: &str =
+    "Endpoint can only be called by admin or an address with the required role";
+
+/// The contract admin implicitly has every role,
+/// and the Admin role has the permissions of every other role.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum Role {
+    Admin,
+    WhitelistManager,
+    Treasurer,
+    Pauser,
+}
+
+#[elrond_wasm::module]
+pub trait RolesModule: crate::admin::AdminModule {
+    /// Only the admin itself can grant or revoke the Admin role.
+    #[endpoint]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_role_manager(role);
+
+        if self.role_members(&role).insert(address.clone()) {
+            self.role_granted_event(&role, &address);
+        }
+    }
+
+    #[endpoint]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_role_manager(role);
+
+        if self.role_members(&role).remove(&address) {
+            self.role_revoked_event(&role, &address);
+        }
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        return self.role_members(&role).contains(address);
+    }
+
+    #[view(getRoleMembers)]
+    fn get_role_members(&self, role: Role) -> MultiValueEncoded<ManagedAddress> {
+        let mut members = MultiValueEncoded::new();
+
+        for address in self.role_members(&role).iter() {
+            members.push(address);
+        }
+
+        return members;
+    }
+
+    fn require_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+
+        require!(
+            caller == self.get_admin()
+                || self.has_role(Role::Admin, &caller)
+                || self.has_role(role, &caller),
+            ERR_MISSING_ROLE
+        );
+    }
+
+    fn require_role_manager(&self, role: Role) {
+        if role == Role::Admin {
+            self.require_contract_admin();
+        } else {
+            self.require_admin();
+        }
+    }
+
+    #[event("role_granted")]
+    fn role_granted_event(&self, #[indexed] role: &Role, #[indexed] address: &ManagedAddress);
+
+    #[event("role_revoked")]
+    fn role_revoked_event(&self, #[indexed] role: &Role, #[indexed] address: &ManagedAddress);
+}
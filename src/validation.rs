@@ -0,0 +1,90 @@
+elrond_wasm::imports!();
+
+use crate::config::{SaleConfig, SaleSchedule};
+use crate::{
+    ERR_INIT_CLOSE_BEFORE_OPEN, ERR_INIT_CLOSE_OVERFLOW, ERR_INIT_FIRST_WL_UNDERFLOW,
+    ERR_INIT_PRICE_PER_EGG_DIFF, ERR_INIT_PRICE_PER_EGG_ZERO, ERR_INIT_REDUCED_PRICE_PER_EGG_DIFF,
+    ERR_INIT_REDUCED_PRICE_PER_EGG_ZERO, ERR_INIT_SECOND_WL_LESSER_THEN_FIRST,
+    ERR_INIT_ZERO_SUPPLY, ERR_SCHEDULE_PUBLIC_BEFORE_SECOND_WL,
+    ERR_SCHEDULE_SECOND_WL_BEFORE_FIRST,
+};
+
+/// The checks shared by the sale creation and the factory deploying new sale contracts.
+#[elrond_wasm::module]
+pub trait ValidationModule {
+    fn require_valid_sale_config(&self, config: &SaleConfig<Self::Api>) {
+        require!(
+            config
+                .max_per_wallet_first_whitelist
+                .max(config.max_per_wallet_second_whitelist)
+                .max(config.max_per_wallet_public)
+                > 0,
+            ERR_INIT_ZERO_SUPPLY
+        );
+
+        require!(config.price_per_egg.len() > 0, ERR_INIT_PRICE_PER_EGG_ZERO);
+
+        require!(
+            config.reduced_price_per_egg.len() > 0,
+            ERR_INIT_REDUCED_PRICE_PER_EGG_ZERO
+        );
+
+        // the full price list is used by both the first whitelist and the public buyers
+        let max_per_wallet_full_price = config
+            .max_per_wallet_first_whitelist
+            .max(config.max_per_wallet_public);
+
+        require!(
+            config.price_per_egg.len() == max_per_wallet_full_price as usize,
+            ERR_INIT_PRICE_PER_EGG_DIFF
+        );
+
+        require!(
+            config.reduced_price_per_egg.len() == config.max_per_wallet_second_whitelist as usize,
+            ERR_INIT_REDUCED_PRICE_PER_EGG_DIFF
+        );
+
+        if let Some(schedule) = &config.schedule {
+            self.require_valid_sale_schedule(schedule);
+            return;
+        }
+
+        require!(
+            config.second_whitelist_delta <= config.first_whitelist_delta,
+            ERR_INIT_SECOND_WL_LESSER_THEN_FIRST
+        );
+
+        // the second whitelist opens after the first one, so it can't underflow either
+        require!(
+            config.first_whitelist_delta <= config.timestamp_public_sale,
+            ERR_INIT_FIRST_WL_UNDERFLOW
+        );
+
+        require!(config.sale_duration > 0, ERR_INIT_CLOSE_BEFORE_OPEN);
+
+        require!(
+            config
+                .timestamp_public_sale
+                .checked_add(config.sale_duration)
+                .is_some(),
+            ERR_INIT_CLOSE_OVERFLOW
+        );
+    }
+
+    fn require_valid_sale_schedule(&self, schedule: &SaleSchedule) {
+        require!(
+            schedule.timestamp_first_whitelist <= schedule.timestamp_second_whitelist,
+            ERR_SCHEDULE_SECOND_WL_BEFORE_FIRST
+        );
+
+        require!(
+            schedule.timestamp_second_whitelist <= schedule.timestamp_public_sale,
+            ERR_SCHEDULE_PUBLIC_BEFORE_SECOND_WL
+        );
+
+        require!(
+            schedule.timestamp_public_sale < schedule.timestamp_sale_closed,
+            ERR_INIT_CLOSE_BEFORE_OPEN
+        );
+    }
+}
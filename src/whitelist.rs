@@ -1,36 +1,122 @@
 elrond_wasm::imports!();
 elrond_wasm::derive_imports!();
 
+use crate::roles::Role;
+
 pub const ERR_NOT_OWNER: &str = "Endpoint can only be called by owner";
+pub const ERR_WHITELIST_END_BEFORE_START: &str = "A whitelist can't end before it starts";
+pub const ERR_WHITELIST_END_AFTER_PUBLIC: &str =
+    "A whitelist can't end after the public sale opens";
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Clone, Copy)]
+pub enum BuyerTier {
+    FirstWhitelist,
+    SecondWhitelist,
+    Public,
+}
 
 #[elrond_wasm::module]
-pub trait WhitelistModule {
+pub trait WhitelistModule: crate::holders::HoldersModule + crate::roles::RolesModule {
     #[view(timestamp_public_sale)]
     #[storage_mapper("timestamp_public_sale")]
-    fn timestamp_public_sale(&self) -> SingleValueMapper<u64>;
+    fn timestamp_public_sale(&self, sale_id: u64) -> SingleValueMapper<u64>;
 
     #[view(timestamp_second_whitelist)]
     #[storage_mapper("timestamp_second_whitelist")]
-    fn timestamp_second_whitelist(&self) -> SingleValueMapper<u64>;
+    fn timestamp_second_whitelist(&self, sale_id: u64) -> SingleValueMapper<u64>;
 
     #[view(timestamp_first_whitelist)]
     #[storage_mapper("timestamp_first_whitelist")]
-    fn timestamp_first_whitelist(&self) -> SingleValueMapper<u64>;
+    fn timestamp_first_whitelist(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    /// When empty, the first whitelist stays open until the public sale.
+    #[view(timestamp_first_whitelist_end)]
+    #[storage_mapper("timestamp_first_whitelist_end")]
+    fn timestamp_first_whitelist_end(&self, sale_id: u64) -> SingleValueMapper<u64>;
 
+    /// When empty, the second whitelist stays open until the public sale.
+    #[view(timestamp_second_whitelist_end)]
+    #[storage_mapper("timestamp_second_whitelist_end")]
+    fn timestamp_second_whitelist_end(&self, sale_id: u64) -> SingleValueMapper<u64>;
+
+    /// None removes the end of the whitelist.
     #[endpoint]
-    fn has_access(&self, address: &ManagedAddress) -> bool {
+    fn set_whitelist_ends(
+        &self,
+        sale_id: u64,
+        first_whitelist_end: Option<u64>,
+        second_whitelist_end: Option<u64>,
+    ) {
+        self.require_admin();
+
+        self.set_whitelist_end(
+            self.timestamp_first_whitelist_end(sale_id),
+            self.timestamp_first_whitelist(sale_id).get(),
+            self.timestamp_public_sale(sale_id).get(),
+            first_whitelist_end,
+        );
+        self.set_whitelist_end(
+            self.timestamp_second_whitelist_end(sale_id),
+            self.timestamp_second_whitelist(sale_id).get(),
+            self.timestamp_public_sale(sale_id).get(),
+            second_whitelist_end,
+        );
+    }
+
+    fn set_whitelist_end(
+        &self,
+        end_mapper: SingleValueMapper<u64>,
+        start: u64,
+        public_sale_timestamp: u64,
+        end: Option<u64>,
+    ) {
+        match end {
+            Some(end) => {
+                require!(end > start, ERR_WHITELIST_END_BEFORE_START);
+                require!(end <= public_sale_timestamp, ERR_WHITELIST_END_AFTER_PUBLIC);
+
+                end_mapper.set(end);
+            }
+            None => end_mapper.clear(),
+        }
+    }
+
+    fn is_first_whitelist_open(&self, sale_id: u64) -> bool {
+        return self.is_window_open(
+            self.timestamp_first_whitelist(sale_id).get(),
+            self.timestamp_first_whitelist_end(sale_id),
+        );
+    }
+
+    fn is_second_whitelist_open(&self, sale_id: u64) -> bool {
+        return self.is_window_open(
+            self.timestamp_second_whitelist(sale_id).get(),
+            self.timestamp_second_whitelist_end(sale_id),
+        );
+    }
+
+    fn is_window_open(&self, start: u64, end_mapper: SingleValueMapper<u64>) -> bool {
+        let now = self.blockchain().get_block_timestamp();
+
+        return now >= start && (end_mapper.is_empty() || now < end_mapper.get());
+    }
+
+    #[endpoint]
+    fn has_access(&self, sale_id: u64, address: &ManagedAddress) -> bool {
         let now = self.blockchain().get_block_timestamp();
 
-        let public_sale_timestamp = self.timestamp_public_sale().get();
+        let public_sale_timestamp = self.timestamp_public_sale(sale_id).get();
 
         if now >= public_sale_timestamp {
             return true;
-        } else if now >= self.timestamp_second_whitelist().get()
-            && self.check_contains_second(&address)
+        } else if self.is_second_whitelist_open(sale_id)
+            && self.check_contains_second(sale_id, &address)
         {
             return true;
-        } else if now >= self.timestamp_first_whitelist().get()
-            && self.check_contains_first(&address)
+        } else if self.is_holder_sale_open(sale_id) && self.is_verified_holder(sale_id, &address) {
+            return true;
+        } else if self.is_first_whitelist_open(sale_id)
+            && self.check_contains_first(sale_id, &address)
         {
             return true;
         }
@@ -38,61 +124,75 @@ pub trait WhitelistModule {
         return false;
     }
 
+    /// Without holder discounts, verified holders buy with the reduced prices,
+    /// like the second whitelist.
+    #[view(getBuyerTier)]
+    fn get_buyer_tier(&self, sale_id: u64, address: &ManagedAddress) -> BuyerTier {
+        let has_reduced_price =
+            self.is_verified_holder(sale_id, address) && self.holder_discounts().is_empty();
+
+        if self.check_contains_second(sale_id, address) || has_reduced_price {
+            return BuyerTier::SecondWhitelist;
+        } else if self.check_contains_first(sale_id, address) {
+            return BuyerTier::FirstWhitelist;
+        }
+
+        return BuyerTier::Public;
+    }
+
     // ===
     // FIRST WHITELIST
     #[endpoint]
-    #[only_owner]
-    fn add_to_first_whitelist(&self, item: &ManagedAddress) {
-        self.blockchain().check_caller_is_owner();
-        self.first_whitelist_mapper().add(item);
+    fn add_to_first_whitelist(&self, sale_id: u64, item: &ManagedAddress) {
+        self.require_role(Role::WhitelistManager);
+        self.first_whitelist_mapper(sale_id).add(item);
     }
 
     #[endpoint]
-    #[only_owner]
-    fn remove_from_first_whitelist(&self, item: &ManagedAddress) {
-        self.blockchain().check_caller_is_owner();
-        self.first_whitelist_mapper().remove(item);
+    fn remove_from_first_whitelist(&self, sale_id: u64, item: &ManagedAddress) {
+        self.require_role(Role::WhitelistManager);
+        self.first_whitelist_mapper(sale_id).remove(item);
     }
 
     #[endpoint]
-    fn check_contains_first(&self, item: &ManagedAddress) -> bool {
-        self.first_whitelist_mapper().contains(item)
+    fn check_contains_first(&self, sale_id: u64, item: &ManagedAddress) -> bool {
+        self.first_whitelist_mapper(sale_id).contains(item)
     }
 
     #[endpoint]
-    fn require_contains_first(&self, item: &ManagedAddress) {
-        self.first_whitelist_mapper().require_whitelisted(item);
+    fn require_contains_first(&self, sale_id: u64, item: &ManagedAddress) {
+        self.first_whitelist_mapper(sale_id)
+            .require_whitelisted(item);
     }
 
     #[storage_mapper("first_whitelist_mapper")]
-    fn first_whitelist_mapper(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+    fn first_whitelist_mapper(&self, sale_id: u64) -> WhitelistMapper<Self::Api, ManagedAddress>;
 
     // ===
     // SECOND WHITELIST
     #[endpoint]
-    #[only_owner]
-    fn add_to_second_whitelist(&self, item: &ManagedAddress) {
-        self.blockchain().check_caller_is_owner();
-        self.second_whitelist_mapper().add(item);
+    fn add_to_second_whitelist(&self, sale_id: u64, item: &ManagedAddress) {
+        self.require_role(Role::WhitelistManager);
+        self.second_whitelist_mapper(sale_id).add(item);
     }
 
     #[endpoint]
-    #[only_owner]
-    fn remove_from_second_whitelist(&self, item: &ManagedAddress) {
-        self.blockchain().check_caller_is_owner();
-        self.second_whitelist_mapper().remove(item);
+    fn remove_from_second_whitelist(&self, sale_id: u64, item: &ManagedAddress) {
+        self.require_role(Role::WhitelistManager);
+        self.second_whitelist_mapper(sale_id).remove(item);
     }
 
     #[endpoint]
-    fn check_contains_second(&self, item: &ManagedAddress) -> bool {
-        self.second_whitelist_mapper().contains(item)
+    fn check_contains_second(&self, sale_id: u64, item: &ManagedAddress) -> bool {
+        self.second_whitelist_mapper(sale_id).contains(item)
     }
 
     #[endpoint]
-    fn require_contains_second(&self, item: &ManagedAddress) {
-        self.second_whitelist_mapper().require_whitelisted(item);
+    fn require_contains_second(&self, sale_id: u64, item: &ManagedAddress) {
+        self.second_whitelist_mapper(sale_id)
+            .require_whitelisted(item);
     }
 
     #[storage_mapper("second_whitelist_mapper")]
-    fn second_whitelist_mapper(&self) -> WhitelistMapper<Self::Api, ManagedAddress>;
+    fn second_whitelist_mapper(&self, sale_id: u64) -> WhitelistMapper<Self::Api, ManagedAddress>;
 }
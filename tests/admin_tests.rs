@@ -0,0 +1,123 @@
+mod contract_setup;
+
+use contract_setup::setup_contract;
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::admin::AdminModule;
+
+#[test]
+fn admin_defaults_to_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_admin(), ManagedAddress::from_address(owner));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn propose_admin_while_not_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_admin(ManagedAddress::from_address(user));
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn accept_admin_while_not_proposed() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let new_admin = &setup.users[0].clone();
+    let other = &setup.users[1].clone();
+
+    setup
+        .accept_admin(new_admin)
+        .assert_user_error(public_sale_mint::admin::ERR_NOT_PENDING_ADMIN);
+
+    setup.propose_admin(new_admin).assert_ok();
+    setup
+        .accept_admin(other)
+        .assert_user_error(public_sale_mint::admin::ERR_NOT_PENDING_ADMIN);
+}
+
+#[test]
+fn cancel_admin_proposal() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+    let new_admin = &setup.users[0].clone();
+
+    setup.propose_admin(new_admin).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.cancel_admin_proposal();
+
+            assert_eq!(sc.pending_admin().is_empty(), true);
+        })
+        .assert_ok();
+
+    setup
+        .accept_admin(new_admin)
+        .assert_user_error(public_sale_mint::admin::ERR_NOT_PENDING_ADMIN);
+}
+
+#[test]
+fn transfer_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+    let new_admin = &setup.users[0].clone();
+
+    setup.propose_admin(new_admin).assert_ok();
+    setup.accept_admin(new_admin).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_admin(), ManagedAddress::from_address(new_admin));
+            assert_eq!(sc.pending_admin().is_empty(), true);
+        })
+        .assert_ok();
+
+    // the previous owner lost its rights
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_admin(ManagedAddress::from_address(owner));
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn claim_balance_goes_to_new_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let new_admin = &setup.users[0].clone();
+    let buyer = &setup.users[1].clone();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup.propose_admin(new_admin).assert_ok();
+    setup.accept_admin(new_admin).assert_ok();
+
+    setup.claim_balance(new_admin).assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(new_admin),
+        rust_biguint!(150u64 + 10u64)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(&setup.owner_address),
+        rust_biguint!(0u64)
+    );
+}
@@ -0,0 +1,87 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::elrond_codec::multi_types::MultiValue2;
+use elrond_wasm::types::{ManagedAddress, MultiValueEncoded};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::PublicSaleMint;
+
+#[test]
+fn airdrop_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.fill_eggs(10u64);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            let mut recipients = MultiValueEncoded::new();
+            recipients.push(MultiValue2::from((ManagedAddress::from_address(user), 1)));
+
+            sc.airdrop(SALE_ID, recipients);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn airdrop_to_many() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user1 = &setup.users[0].clone();
+    let user2 = &setup.users[1].clone();
+
+    setup.fill_eggs(10u64);
+    setup.airdrop(&[(user1, 2), (user2, 3)]).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user1), rust_biguint!(2u64));
+    assert_eq!(setup.get_eggs_balance(user2), rust_biguint!(3u64));
+    assert_eq!(setup.get_airdropped_amount(user1), 2);
+    assert_eq!(setup.get_airdropped_amount(user2), 3);
+
+    // airdrops are not purchases
+    assert_eq!(setup.get_buyed_amount(user1), 0);
+    assert_eq!(setup.get_all_buyers().into_iter().next().is_none(), true);
+}
+
+#[test]
+fn airdrop_more_than_balance() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user1 = &setup.users[0].clone();
+    let user2 = &setup.users[1].clone();
+
+    setup.fill_eggs(4u64);
+    setup
+        .airdrop(&[(user1, 2), (user2, 3)])
+        .assert_user_error(public_sale_mint::airdrop::ERR_AIRDROP_NOT_ENOUGH_EGGS);
+
+    assert_eq!(setup.get_eggs_balance(user1), rust_biguint!(0u64));
+    assert_eq!(setup.get_airdropped_amount(user1), 0);
+}
+
+#[test]
+fn airdrop_the_reserve() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_reserved_supply(2);
+    setup.fill_eggs(4u64);
+
+    setup
+        .airdrop(&[(user, 3)])
+        .assert_user_error(public_sale_mint::airdrop::ERR_AIRDROP_NOT_ENOUGH_EGGS);
+    setup.airdrop(&[(user, 2)]).assert_ok();
+}
+
+#[test]
+fn airdrop_zero() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.fill_eggs(10u64);
+    setup
+        .airdrop(&[(user, 0)])
+        .assert_user_error(public_sale_mint::airdrop::ERR_AIRDROP_ZERO_AMOUNT);
+    setup
+        .airdrop(&[])
+        .assert_user_error(public_sale_mint::airdrop::ERR_AIRDROP_EMPTY);
+}
@@ -0,0 +1,154 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::{elrond_codec::multi_types::OptionalValue, types::ManagedAddress};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::overrides::OverridesModule;
+
+#[test]
+fn set_override_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_allocation_override(
+                SALE_ID,
+                ManagedAddress::from_address(user),
+                10,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn set_override_with_zero_max_per_wallet() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .set_allocation_override(user, 0, None)
+        .assert_user_error(public_sale_mint::overrides::ERR_OVERRIDE_MAX_PER_WALLET_ZERO);
+}
+
+#[test]
+fn buy_more_than_max_per_wallet_with_custom_price() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_allocation_override(user, 8, Some(7)).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    assert_eq!(setup.get_max_per_wallet(user), 8);
+
+    setup.buy(user, &rust_biguint!(7u64 * 6), 6).assert_ok();
+    setup.buy(user, &rust_biguint!(7u64 * 2), 2).assert_ok();
+    setup
+        .buy(user, &rust_biguint!(7u64), 1)
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+
+    assert_eq!(setup.get_buyed_amount(user), 8);
+}
+
+#[test]
+fn buy_with_price_list_while_custom_price() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_allocation_override(user, 5, Some(7)).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy(user, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::ERR_BAD_AMOUNT_SENT);
+}
+
+#[test]
+fn lower_max_per_wallet_without_custom_price() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_allocation_override(user, 1, None).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy(user, &rust_biguint!(9u64 * 2), 2)
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+}
+
+#[test]
+fn buy_more_than_price_list_without_custom_price() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_allocation_override(user, 8, None).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    assert_eq!(setup.get_max_per_wallet(user), 8);
+
+    setup.buy(user, &rust_biguint!(7u64 * 4), 4).assert_ok();
+
+    // the 6th egg and the next ones cost the last price of the list
+    setup.buy(user, &rust_biguint!(6u64 * 3), 3).assert_ok();
+    setup.buy(user, &rust_biguint!(6u64), 1).assert_ok();
+    setup
+        .buy(user, &rust_biguint!(6u64), 1)
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+
+    assert_eq!(setup.get_buyed_amount(user), 8);
+}
+
+#[test]
+fn remove_override() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+
+    setup.set_allocation_override(user, 8, Some(7)).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.remove_allocation_override(SALE_ID, ManagedAddress::from_address(user));
+        })
+        .assert_ok();
+
+    assert_eq!(setup.get_max_per_wallet(user), 5);
+}
+
+#[test]
+fn get_overrides() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user1 = &setup.users[0].clone();
+    let user2 = &setup.users[1].clone();
+
+    setup.set_allocation_override(user1, 8, Some(7)).assert_ok();
+    setup.set_allocation_override(user2, 2, None).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let overrides = sc.get_allocation_overrides(SALE_ID);
+            let mut overrides_iter = overrides.into_iter();
+
+            let (address, allocation_override) = overrides_iter.next().unwrap().into_tuple();
+            assert_eq!(address, ManagedAddress::from_address(user1));
+            assert_eq!(allocation_override.max_per_wallet, 8);
+            assert_eq!(allocation_override.price_per_egg.unwrap(), 7u64);
+
+            let (address, allocation_override) = overrides_iter.next().unwrap().into_tuple();
+            assert_eq!(address, ManagedAddress::from_address(user2));
+            assert_eq!(allocation_override.max_per_wallet, 2);
+            assert_eq!(allocation_override.price_per_egg.is_none(), true);
+
+            assert_eq!(overrides_iter.next().is_none(), true);
+        })
+        .assert_ok();
+}
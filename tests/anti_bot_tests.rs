@@ -0,0 +1,81 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, WASM_PATH};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::anti_bot::AntiBotModule;
+
+#[test]
+fn buy_from_smart_contract() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    let bot_contract = setup.blockchain_wrapper.create_sc_account(
+        &rust_biguint!(150u64),
+        Some(owner),
+        public_sale_mint::contract_obj,
+        WASM_PATH,
+    );
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy(bot_contract.address_ref(), &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::anti_bot::ERR_CALLER_IS_SMART_CONTRACT);
+}
+
+#[test]
+fn buy_more_than_block_limit() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user1 = &setup.users[0].clone();
+    let user2 = &setup.users[1].clone();
+
+    setup.set_anti_bot_limits(2, 0).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup.blockchain_wrapper.set_block_nonce(1);
+    setup.buy(user1, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+    setup
+        .buy(user2, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::anti_bot::ERR_BLOCK_LIMIT_REACHED);
+
+    setup.blockchain_wrapper.set_block_nonce(2);
+    setup.buy(user2, &rust_biguint!(10u64), 1).assert_ok();
+}
+
+#[test]
+fn buy_again_too_soon() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    const COOLDOWN: u64 = 5;
+
+    setup.set_anti_bot_limits(0, COOLDOWN).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+    setup
+        .buy(user, &rust_biguint!(9u64), 1)
+        .assert_user_error(public_sale_mint::anti_bot::ERR_PURCHASE_TOO_SOON);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(setup.public_timestamp + COOLDOWN);
+    setup.buy(user, &rust_biguint!(9u64), 1).assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(user), 2);
+}
+
+#[test]
+fn set_anti_bot_limits_while_not_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_anti_bot_limits(1, 1);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
@@ -1,7 +1,8 @@
 mod contract_setup;
 
-use contract_setup::setup_contract;
+use contract_setup::{setup_contract, SALE_ID};
 use elrond_wasm::contract_base::ContractBase;
+use elrond_wasm::elrond_codec::multi_types::OptionalValue;
 use elrond_wasm_debug::rust_biguint;
 use public_sale_mint::PublicSaleMint;
 
@@ -64,7 +65,15 @@ fn buy_with_not_egld() {
             |sc| {
                 let payment = sc.call_value().payment_as_tuple();
 
-                sc.buy(payment.2, payment.0, payment.1, 1u64);
+                sc.buy(
+                    payment.2,
+                    payment.0,
+                    payment.1,
+                    SALE_ID,
+                    1u64,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
             },
         )
         .assert_user_error(public_sale_mint::ERR_BUY_NOT_EGLD);
@@ -1,14 +1,17 @@
 mod contract_setup;
 
 use contract_setup::setup_contract;
+use elrond_wasm::elrond_codec::multi_types::MultiValue2;
+use elrond_wasm::types::{ManagedAddress, MultiValueEncoded};
 use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::revenue::RevenueModule;
 
 #[test]
 fn claim_balance_while_not_owner() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
     setup
         .claim_balance(&setup.users[1].clone())
-        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
 }
 
 #[test]
@@ -32,3 +35,89 @@ fn claim_balance_while_owner() {
         rust_biguint!(9u64 + 9u64)
     );
 }
+
+#[test]
+fn set_payees_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            let mut payees = MultiValueEncoded::new();
+            payees.push(MultiValue2::from((
+                ManagedAddress::from_address(user),
+                10_000,
+            )));
+
+            sc.set_payees(payees);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn set_payees_with_bad_shares() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let treasury = &setup.users[2].clone();
+    let charity = &setup.users[3].clone();
+
+    setup
+        .set_payees(&[(treasury, 9_000), (charity, 500)])
+        .assert_user_error(public_sale_mint::revenue::ERR_PAYEES_SHARES_SUM);
+    setup
+        .set_payees(&[(treasury, 10_000), (charity, 0)])
+        .assert_user_error(public_sale_mint::revenue::ERR_PAYEE_SHARE_ZERO);
+    setup
+        .set_payees(&[(treasury, 5_000), (treasury, 5_000)])
+        .assert_user_error(public_sale_mint::revenue::ERR_PAYEE_DUPLICATE);
+}
+
+#[test]
+fn claim_balance_split_between_payees() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let treasury = &setup.users[2].clone();
+    let charity = &setup.users[3].clone();
+
+    setup
+        .set_payees(&[(treasury, 7_500), (charity, 2_500)])
+        .assert_ok();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    setup
+        .claim_balance(&setup.owner_address.clone())
+        .assert_ok();
+
+    // 18 * 75% = 13.5, the rounding remainder goes to the last payee
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(treasury),
+        rust_biguint!(150u64 + 13u64)
+    );
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(charity),
+        rust_biguint!(150u64 + 5u64)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(&setup.owner_address),
+        rust_biguint!(0u64)
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(
+                sc.get_released(&ManagedAddress::from_address(treasury)),
+                13u64
+            );
+            assert_eq!(
+                sc.get_released(&ManagedAddress::from_address(charity)),
+                5u64
+            );
+        })
+        .assert_ok();
+}
@@ -1,6 +1,6 @@
 mod contract_setup;
 
-use contract_setup::setup_contract;
+use contract_setup::{setup_contract, SALE_ID};
 use elrond_wasm_debug::rust_biguint;
 use public_sale_mint::PublicSaleMint;
 
@@ -15,10 +15,10 @@ fn claim_balance_while_not_owner() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.claim_eggs();
+                sc.claim_eggs(SALE_ID);
             },
         )
-        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
 }
 
 #[test]
@@ -34,7 +34,7 @@ fn claim_balance_while_owner() {
             &setup.contract_wrapper,
             &rust_biguint!(0),
             |sc| {
-                sc.claim_eggs();
+                sc.claim_eggs(SALE_ID);
             },
         )
         .assert_ok();
@@ -44,3 +44,34 @@ fn claim_balance_while_owner() {
         rust_biguint!(10u64)
     );
 }
+
+#[test]
+fn claim_eggs_keeps_pending_and_reserved_eggs() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_reserved_supply(3);
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(9u64 * 2), 2).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.claim_eggs(SALE_ID);
+        })
+        .assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(owner), rust_biguint!(10u64 - 3 - 2));
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(claim_timestamp);
+    setup.claim(buyer).assert_ok();
+    setup.mint_reserved(owner, 3).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(buyer), rust_biguint!(2u64));
+}
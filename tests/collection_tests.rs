@@ -0,0 +1,177 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, EGG_ID, SALE_ID};
+use elrond_wasm::types::{
+    BigUint, EsdtLocalRole, ManagedAsyncCallResult, ManagedBuffer, MultiValueEncoded,
+    TokenIdentifier,
+};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::PublicSaleMint;
+
+const NEW_EGG_ID: &[u8] = b"EGG-123456";
+
+#[test]
+fn issue_egg_collection_already_issued() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.issue_egg_collection(
+                    BigUint::zero(),
+                    SALE_ID,
+                    ManagedBuffer::from(b"Eggs"),
+                    ManagedBuffer::from(b"EGG"),
+                );
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_COLLECTION_ALREADY_ISSUED);
+}
+
+#[test]
+fn issue_egg_collection_while_issuing() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                // the callback of a first issue hasn't run yet
+                sc.token_identifier(SALE_ID).clear();
+                sc.collection_issuing(SALE_ID).set(true);
+
+                sc.issue_egg_collection(
+                    BigUint::zero(),
+                    SALE_ID,
+                    ManagedBuffer::from(b"Eggs"),
+                    ManagedBuffer::from(b"EGG"),
+                );
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_COLLECTION_BEING_ISSUED);
+}
+
+#[test]
+fn issue_egg_collection_as_user() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.issue_egg_collection(
+                BigUint::zero(),
+                SALE_ID,
+                ManagedBuffer::from(b"Eggs"),
+                ManagedBuffer::from(b"EGG"),
+            );
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn issue_callback_stores_token_identifier() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.token_identifier(SALE_ID).clear();
+                sc.collection_issuing(SALE_ID).set(true);
+
+                sc.issue_callback(
+                    SALE_ID,
+                    ManagedAsyncCallResult::Ok(TokenIdentifier::from_esdt_bytes(NEW_EGG_ID)),
+                );
+
+                assert_eq!(
+                    sc.token_identifier(SALE_ID).get(),
+                    TokenIdentifier::from_esdt_bytes(NEW_EGG_ID)
+                );
+                assert_eq!(sc.collection_issuing(SALE_ID).get(), false);
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn create_egg_nonce_already_created() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.create_egg_nonce(
+                    SALE_ID,
+                    10,
+                    ManagedBuffer::from(b"Egg"),
+                    BigUint::zero(),
+                    ManagedBuffer::new(),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_EGG_NONCE_ALREADY_CREATED);
+}
+
+#[test]
+fn create_egg_nonce() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.contract_wrapper.address_ref(),
+        &EGG_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity],
+    );
+
+    let mut nonce = 0u64;
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.token_nonce(SALE_ID).set(0);
+
+                let mut uris = MultiValueEncoded::new();
+                uris.push(ManagedBuffer::from(b"https://example.com/egg.png"));
+
+                nonce = sc.create_egg_nonce(
+                    SALE_ID,
+                    10,
+                    ManagedBuffer::from(b"Egg"),
+                    BigUint::zero(),
+                    ManagedBuffer::from(b"metadata:egg.json"),
+                    uris,
+                );
+
+                assert_eq!(sc.token_nonce(SALE_ID).get(), nonce);
+            },
+        )
+        .assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_esdt_balance(
+            setup.contract_wrapper.address_ref(),
+            &EGG_ID,
+            nonce
+        ),
+        rust_biguint!(10u64)
+    );
+}
@@ -1,21 +1,50 @@
 use elrond_wasm::api::{BigIntApi, ManagedTypeApi};
-use elrond_wasm::elrond_codec::multi_types::MultiValue2;
+use elrond_wasm::elrond_codec::multi_types::{MultiValue2, OptionalValue};
+use elrond_wasm::storage::{
+    mappers::{MapMapper, SingleValueMapper, StorageMapper, VecMapper, WhitelistMapper},
+    StorageKey,
+};
 use elrond_wasm::types::MultiValueEncoded;
 use elrond_wasm::{
     contract_base::ContractBase,
-    types::{Address, BigUint, ManagedAddress, ManagedType, ManagedVec, TokenIdentifier},
+    types::{
+        Address, BigUint, EsdtLocalRole, ManagedAddress, ManagedBuffer, ManagedType, ManagedVec,
+        TokenIdentifier,
+    },
 };
-use elrond_wasm_debug::tx_mock::TxContextRef;
+use elrond_wasm_debug::tx_mock::{TxContextRef, TxInputESDT};
 use elrond_wasm_debug::{rust_biguint, testing_framework::*, tx_mock::TxResult, DebugApi};
-use public_sale_mint::{whitelist::WhitelistModule, *};
+use public_sale_mint::{
+    admin::AdminModule,
+    airdrop::AirdropModule,
+    allocation::AllocationModule,
+    config::SaleConfig,
+    delayed_claim::DelayedClaimModule,
+    holders::HoldersModule,
+    multisig::{MultisigModule, WithdrawalAsset},
+    overrides::OverridesModule,
+    phase::SalePhase,
+    promo::PromoModule,
+    referral::ReferralModule,
+    reserve::ReserveModule,
+    revenue::RevenueModule,
+    roles::{Role, RolesModule},
+    whitelist::WhitelistModule,
+    *,
+};
 
 pub const WASM_PATH: &'static str = "output/empty.wasm";
+pub const SALE_ID: u64 = 1;
 pub const PUBLIC_TIMESTAMP: u64 = 120;
 pub const SECOND_WHITELIST_TIMESTAMP_DELTA: u64 = 20;
 pub const FIRST_WHITELIST_TIMESTAMP_DELTA: u64 = 40;
 pub const SALE_DURATION: u64 = 140;
 pub const EGG_ID: [u8; 3] = *b"EGG";
 pub const EGG_NONCE: u64 = 1;
+pub const RESERVED_SUPPLY: u64 = 0;
+pub const PROPOSAL_DURATION: u64 = 50;
+pub const HOLDER_TOKEN_ID: [u8; 7] = *b"PENGUIN";
+pub const HOLDER_SALE_TIMESTAMP_DELTA: u64 = 30;
 
 pub struct ContractSetup<ContractObjBuilder>
 where
@@ -41,12 +70,13 @@ where
     ContractObjBuilder: 'static + Copy + Fn() -> public_sale_mint::ContractObj<DebugApi>,
 {
     #[allow(dead_code)]
-    pub fn get_max_per_wallet(&mut self) -> u64 {
+    pub fn get_max_per_wallet(&mut self, address: &Address) -> u64 {
         let mut output = None;
 
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.max_per_wallet().get());
+                output =
+                    Some(sc.get_max_per_wallet(SALE_ID, &ManagedAddress::from_address(address)));
             })
             .assert_ok();
 
@@ -61,7 +91,7 @@ where
 
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.price_per_egg().get(index + 1));
+                output = Some(sc.price_per_egg(SALE_ID).get(index + 1));
 
                 assert_eq!(output.is_some(), true, "Cannot get the price of the egg");
             })
@@ -79,7 +109,7 @@ where
             &self.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.add_to_first_whitelist(&ManagedAddress::from_address(address));
+                sc.add_to_first_whitelist(SALE_ID, &ManagedAddress::from_address(address));
             },
         );
 
@@ -93,7 +123,7 @@ where
             &self.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.add_to_second_whitelist(&ManagedAddress::from_address(address));
+                sc.add_to_second_whitelist(SALE_ID, &ManagedAddress::from_address(address));
             },
         );
 
@@ -105,7 +135,8 @@ where
         let mut output = Option::None;
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.check_contains_first(&ManagedAddress::from_address(&address)));
+                output =
+                    Some(sc.check_contains_first(SALE_ID, &ManagedAddress::from_address(&address)));
             })
             .assert_ok();
 
@@ -117,7 +148,9 @@ where
         let mut output = Option::None;
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.check_contains_second(&ManagedAddress::from_address(&address)));
+                output = Some(
+                    sc.check_contains_second(SALE_ID, &ManagedAddress::from_address(&address)),
+                );
             })
             .assert_ok();
 
@@ -131,7 +164,7 @@ where
             &self.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.remove_from_first_whitelist(&ManagedAddress::from_address(&address));
+                sc.remove_from_first_whitelist(SALE_ID, &ManagedAddress::from_address(&address));
             },
         );
 
@@ -145,7 +178,7 @@ where
             &self.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.remove_from_second_whitelist(&ManagedAddress::from_address(&address));
+                sc.remove_from_second_whitelist(SALE_ID, &ManagedAddress::from_address(&address));
             },
         );
 
@@ -207,6 +240,24 @@ where
             .assert_ok();
     }
 
+    #[allow(dead_code)]
+    pub fn enable_mint_on_demand(&mut self, max_supply: u64) -> TxResult {
+        self.blockchain_wrapper.set_esdt_local_roles(
+            self.contract_wrapper.address_ref(),
+            &self.egg_id,
+            &[EsdtLocalRole::NftAddQuantity],
+        );
+
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.enable_mint_on_demand(SALE_ID, max_supply);
+            },
+        );
+    }
+
     #[allow(dead_code)]
     pub fn buy(&mut self, address: &Address, egld: &num_bigint::BigUint, to_buy: u64) -> TxResult {
         return self
@@ -214,10 +265,120 @@ where
             .execute_tx(address, &self.contract_wrapper, egld, |sc| {
                 let payment = sc.call_value().payment_as_tuple();
 
-                sc.buy(payment.2, payment.0, payment.1, to_buy);
+                sc.buy(
+                    payment.2,
+                    payment.0,
+                    payment.1,
+                    SALE_ID,
+                    to_buy,
+                    OptionalValue::None,
+                    OptionalValue::None,
+                );
+            });
+    }
+
+    #[allow(dead_code)]
+    pub fn buy_with_referrer(
+        &mut self,
+        address: &Address,
+        egld: &num_bigint::BigUint,
+        to_buy: u64,
+        referrer: &Address,
+    ) -> TxResult {
+        return self
+            .blockchain_wrapper
+            .execute_tx(address, &self.contract_wrapper, egld, |sc| {
+                let payment = sc.call_value().payment_as_tuple();
+
+                sc.buy(
+                    payment.2,
+                    payment.0,
+                    payment.1,
+                    SALE_ID,
+                    to_buy,
+                    OptionalValue::Some(ManagedAddress::from_address(referrer)),
+                    OptionalValue::None,
+                );
+            });
+    }
+
+    #[allow(dead_code)]
+    pub fn buy_with_promo_code(
+        &mut self,
+        address: &Address,
+        egld: &num_bigint::BigUint,
+        to_buy: u64,
+        promo_code: &[u8],
+    ) -> TxResult {
+        return self
+            .blockchain_wrapper
+            .execute_tx(address, &self.contract_wrapper, egld, |sc| {
+                let payment = sc.call_value().payment_as_tuple();
+
+                sc.buy(
+                    payment.2,
+                    payment.0,
+                    payment.1,
+                    SALE_ID,
+                    to_buy,
+                    OptionalValue::Some(ManagedAddress::zero()),
+                    OptionalValue::Some(ManagedBuffer::from(promo_code)),
+                );
             });
     }
 
+    #[allow(dead_code)]
+    pub fn register_promo_code(
+        &mut self,
+        promo_code: &[u8],
+        discount: u64,
+        usage_limit: u64,
+        expiration: u64,
+    ) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let code_hash = sc
+                    .crypto()
+                    .sha256(&ManagedBuffer::from(promo_code))
+                    .as_managed_buffer()
+                    .clone();
+
+                sc.register_promo_code(code_hash, discount, usage_limit, expiration);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_referral_program(
+        &mut self,
+        referral_reward: u64,
+        referee_discount: u64,
+    ) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_referral_program(referral_reward, referee_discount);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn claim_referral_rewards(&mut self, address: &Address) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.claim_referral_rewards();
+            },
+        );
+    }
+
     #[allow(dead_code)]
     pub fn get_eggs_balance(&mut self, address: &Address) -> num_bigint::BigUint {
         return self
@@ -230,7 +391,8 @@ where
         let mut output = Option::None;
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.get_bought_amount(&ManagedAddress::from_address(address)));
+                output =
+                    Some(sc.get_bought_amount(SALE_ID, &ManagedAddress::from_address(address)));
             })
             .assert_ok();
 
@@ -258,7 +420,7 @@ where
         let mut output = Option::None;
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.get_all_buyers());
+                output = Some(sc.get_all_buyers(SALE_ID));
             })
             .assert_ok();
 
@@ -278,7 +440,499 @@ where
             |sc| {
                 let payment = sc.call_value().payment_as_tuple();
 
-                sc.fill_egg(payment.2, payment.0, payment.1);
+                sc.fill_egg(payment.2, payment.0, payment.1, SALE_ID);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_phase_allocation(&mut self, phase: SalePhase, allocation: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_phase_allocation(SALE_ID, phase, allocation);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn get_remaining_allocation(&mut self, phase: SalePhase) -> Option<u64> {
+        let mut output = Option::None;
+        self.blockchain_wrapper
+            .execute_query(&self.contract_wrapper, |sc| {
+                output = Some(sc.get_remaining_allocation(SALE_ID, phase));
+            })
+            .assert_ok();
+
+        return output.unwrap();
+    }
+
+    #[allow(dead_code)]
+    pub fn set_allocation_override(
+        &mut self,
+        address: &Address,
+        max_per_wallet: u64,
+        price_per_egg: Option<u64>,
+    ) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let price_per_egg = match price_per_egg {
+                    Some(price) => OptionalValue::Some(BigUint::from(price)),
+                    None => OptionalValue::None,
+                };
+
+                sc.set_allocation_override(
+                    SALE_ID,
+                    ManagedAddress::from_address(address),
+                    max_per_wallet,
+                    price_per_egg,
+                );
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn airdrop(&mut self, recipients: &[(&Address, u64)]) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+
+                for (address, amount) in recipients.iter() {
+                    args.push(MultiValue2::from((
+                        ManagedAddress::from_address(address),
+                        *amount,
+                    )));
+                }
+
+                sc.airdrop(SALE_ID, args);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn get_airdropped_amount(&mut self, address: &Address) -> u64 {
+        let mut output = Option::None;
+        self.blockchain_wrapper
+            .execute_query(&self.contract_wrapper, |sc| {
+                output =
+                    Some(sc.get_airdropped_amount(SALE_ID, &ManagedAddress::from_address(address)));
+            })
+            .assert_ok();
+
+        return output.unwrap();
+    }
+
+    #[allow(dead_code)]
+    pub fn set_anti_bot_limits(&mut self, max_per_block: u64, purchase_cooldown: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_anti_bot_limits(max_per_block, purchase_cooldown);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_holder_sale(&mut self) {
+        self.set_holder_sale_at(self.public_timestamp - HOLDER_SALE_TIMESTAMP_DELTA)
+            .assert_ok();
+    }
+
+    #[allow(dead_code)]
+    pub fn set_holder_sale_at(&mut self, timestamp_holder_sale: u64) -> TxResult {
+        let tx_result = self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_holder_sale(
+                    SALE_ID,
+                    TokenIdentifier::from_esdt_bytes(&HOLDER_TOKEN_ID),
+                    timestamp_holder_sale,
+                );
+            },
+        );
+
+        return tx_result;
+    }
+
+    #[allow(dead_code)]
+    pub fn set_holder_tokens(&mut self, address: &Address, nonces: &[u64]) {
+        for nonce in nonces.iter() {
+            self.blockchain_wrapper.set_nft_balance(
+                address,
+                &HOLDER_TOKEN_ID,
+                *nonce,
+                &rust_biguint!(1u64),
+                &{},
+            );
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn prove_holding(&mut self, address: &Address, nonces: &[u64]) -> TxResult {
+        let transfers: Vec<TxInputESDT> = nonces
+            .iter()
+            .map(|nonce| TxInputESDT {
+                token_identifier: HOLDER_TOKEN_ID.to_vec(),
+                nonce: *nonce,
+                value: rust_biguint!(1u64),
+            })
+            .collect();
+
+        return self.blockchain_wrapper.execute_esdt_multi_transfer(
+            address,
+            &self.contract_wrapper,
+            &transfers,
+            |sc| {
+                sc.prove_holding(SALE_ID);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_holder_discounts(&mut self, discounts: &[(u64, u64)]) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+
+                for (min_held, discount) in discounts.iter() {
+                    args.push(MultiValue2::from((*min_held, *discount)));
+                }
+
+                sc.set_holder_discounts(args);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_board(&mut self, quorum: usize, members: &[&Address]) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+
+                for address in members.iter() {
+                    args.push(ManagedAddress::from_address(address));
+                }
+
+                sc.set_board(quorum, PROPOSAL_DURATION, args);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn propose_withdrawal(
+        &mut self,
+        caller: &Address,
+        asset: WithdrawalAsset,
+        amount: u64,
+        destination: &Address,
+    ) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            caller,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.propose_withdrawal(
+                    SALE_ID,
+                    asset,
+                    BigUint::from(amount),
+                    ManagedAddress::from_address(destination),
+                );
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn sign_withdrawal(&mut self, caller: &Address, proposal_id: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            caller,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.sign_withdrawal(proposal_id);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn execute_withdrawal(&mut self, caller: &Address, proposal_id: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            caller,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.execute_withdrawal(proposal_id);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn propose_admin(&mut self, address: &Address) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.propose_admin(ManagedAddress::from_address(address));
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn accept_admin(&mut self, caller: &Address) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            caller,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.accept_admin();
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn grant_role(&mut self, role: Role, address: &Address) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.grant_role(role, ManagedAddress::from_address(address));
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn revoke_role(&mut self, role: Role, address: &Address) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.revoke_role(role, ManagedAddress::from_address(address));
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_forward_revenue(&mut self, forward_revenue: bool) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_forward_revenue(forward_revenue);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_vesting_schedule(&mut self, initial_unlock: u64, duration: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_vesting_schedule(initial_unlock, duration);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_payees(&mut self, payees: &[(&Address, u64)]) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+
+                for (address, share) in payees.iter() {
+                    args.push(MultiValue2::from((
+                        ManagedAddress::from_address(address),
+                        *share,
+                    )));
+                }
+
+                sc.set_payees(args);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_claim_timestamp(&mut self, claim_timestamp: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_claim_timestamp(SALE_ID, OptionalValue::Some(claim_timestamp));
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn undelay_claim(&mut self) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_claim_timestamp(SALE_ID, OptionalValue::None);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn claim(&mut self, address: &Address) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.claim(SALE_ID);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn get_pending_eggs(&mut self, address: &Address) -> u64 {
+        let mut output = Option::None;
+        self.blockchain_wrapper
+            .execute_query(&self.contract_wrapper, |sc| {
+                output = Some(sc.get_pending_eggs(SALE_ID, &ManagedAddress::from_address(address)));
+            })
+            .assert_ok();
+
+        return output.unwrap();
+    }
+
+    #[allow(dead_code)]
+    pub fn set_reserved_supply(&mut self, reserved_supply: u64) {
+        self.blockchain_wrapper
+            .execute_tx(
+                &self.owner_address,
+                &self.contract_wrapper,
+                &rust_biguint!(0u64),
+                |sc| {
+                    sc.reserved_supply(SALE_ID).set(reserved_supply);
+                },
+            )
+            .assert_ok();
+    }
+
+    #[allow(dead_code)]
+    pub fn mint_reserved(&mut self, to: &Address, amount: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.mint_reserved(SALE_ID, ManagedAddress::from_address(to), amount);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn set_whitelist_ends(
+        &mut self,
+        first_whitelist_end: Option<u64>,
+        second_whitelist_end: Option<u64>,
+    ) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_whitelist_ends(SALE_ID, first_whitelist_end, second_whitelist_end);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn get_current_phase(&mut self) -> SalePhase {
+        let mut output = Option::None;
+        self.blockchain_wrapper
+            .execute_query(&self.contract_wrapper, |sc| {
+                output = Some(sc.get_current_phase(SALE_ID));
+            })
+            .assert_ok();
+
+        return output.unwrap();
+    }
+
+    #[allow(dead_code)]
+    pub fn postpone_sale(&mut self, caller: &Address, delay: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            caller,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.postpone_sale(SALE_ID, delay);
+            },
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn advance_sale(&mut self, caller: &Address, advance: u64) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            caller,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.advance_sale(SALE_ID, advance);
+            },
+        );
+    }
+
+    /// Purchases as recorded by the contract before the sales were numbered.
+    #[allow(dead_code)]
+    pub fn set_legacy_purchases(&mut self, buyers: &[(&Address, u64)]) {
+        self.blockchain_wrapper
+            .execute_tx(
+                &self.owner_address,
+                &self.contract_wrapper,
+                &rust_biguint!(0u64),
+                |_| {
+                    let mut already_bought =
+                        MapMapper::<DebugApi, ManagedAddress<DebugApi>, u64>::new(StorageKey::new(
+                            b"already_bought",
+                        ));
+
+                    for (address, bought) in buyers {
+                        already_bought.insert(ManagedAddress::from_address(address), *bought);
+                    }
+                },
+            )
+            .assert_ok();
+    }
+
+    /// An upgrade runs init again, without a sale config.
+    #[allow(dead_code)]
+    pub fn upgrade(&mut self) -> TxResult {
+        return self.blockchain_wrapper.execute_tx(
+            &self.owner_address,
+            &self.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.init(OptionalValue::None);
             },
         );
     }
@@ -288,7 +942,7 @@ where
         let mut output = Option::None;
         self.blockchain_wrapper
             .execute_query(&self.contract_wrapper, |sc| {
-                output = Some(sc.has_access(&ManagedAddress::from_address(&address)));
+                output = Some(sc.has_access(SALE_ID, &ManagedAddress::from_address(&address)));
             })
             .assert_ok();
 
@@ -302,6 +956,107 @@ pub fn setup_contract<ContractObjBuilder>(
 ) -> ContractSetup<ContractObjBuilder>
 where
     ContractObjBuilder: 'static + Copy + Fn() -> public_sale_mint::ContractObj<DebugApi>,
+{
+    let mut contract_setup = deploy_contract(cf_builder, |sc, _, _| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 5,
+            max_per_wallet_public: 5,
+            price_per_egg: ManagedVec::<DebugApi, BigUint<DebugApi>>::from(vec![
+                big_uint_conv_num(10),
+                big_uint_conv_num(9),
+                big_uint_conv_num(8),
+                big_uint_conv_num(7),
+                big_uint_conv_num(6),
+            ]),
+            reduced_price_per_egg: ManagedVec::<DebugApi, BigUint<DebugApi>>::from(vec![
+                big_uint_conv_num(5),
+                big_uint_conv_num(4),
+                big_uint_conv_num(3),
+                big_uint_conv_num(2),
+                big_uint_conv_num(1),
+            ]),
+            timestamp_public_sale: PUBLIC_TIMESTAMP,
+            second_whitelist_delta: SECOND_WHITELIST_TIMESTAMP_DELTA,
+            first_whitelist_delta: FIRST_WHITELIST_TIMESTAMP_DELTA,
+            sale_duration: SALE_DURATION,
+            reserved_supply: RESERVED_SUPPLY,
+            schedule: None,
+        }));
+
+        // stands for the collection issued and the nonce created from the contract
+        sc.token_identifier(SALE_ID)
+            .set(TokenIdentifier::from_esdt_bytes(&EGG_ID));
+        sc.token_nonce(SALE_ID).set(EGG_NONCE);
+    });
+
+    contract_setup
+        .add_to_first_whitelist(&contract_setup.user_first_whitelisted.clone())
+        .assert_ok();
+    contract_setup
+        .add_to_second_whitelist(&contract_setup.user_second_whitelisted.clone())
+        .assert_ok();
+
+    return contract_setup;
+}
+
+/// The same sale, deployed with the storage layout written by the contract before the sales
+/// were numbered and the storage was versioned.
+#[allow(dead_code)]
+pub fn setup_legacy_contract<ContractObjBuilder>(
+    cf_builder: ContractObjBuilder,
+) -> ContractSetup<ContractObjBuilder>
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_mint::ContractObj<DebugApi>,
+{
+    return deploy_contract(
+        cf_builder,
+        |_, user_first_whitelisted, user_second_whitelisted| {
+            SingleValueMapper::<DebugApi, u64>::new(StorageKey::new(b"max_per_wallet")).set(5u64);
+
+            let mut price_per_egg =
+                VecMapper::<DebugApi, BigUint<DebugApi>>::new(StorageKey::new(b"price_per_egg"));
+            let mut reduced_price_per_egg = VecMapper::<DebugApi, BigUint<DebugApi>>::new(
+                StorageKey::new(b"reduced_price_per_egg"),
+            );
+            for (price, reduced_price) in [10, 9, 8, 7, 6].iter().zip([5, 4, 3, 2, 1].iter()) {
+                price_per_egg.push(&big_uint_conv_num(*price));
+                reduced_price_per_egg.push(&big_uint_conv_num(*reduced_price));
+            }
+
+            SingleValueMapper::<DebugApi, u64>::new(StorageKey::new(b"timestamp_public_sale"))
+                .set(PUBLIC_TIMESTAMP);
+            SingleValueMapper::<DebugApi, u64>::new(StorageKey::new(b"timestamp_second_whitelist"))
+                .set(PUBLIC_TIMESTAMP - SECOND_WHITELIST_TIMESTAMP_DELTA);
+            SingleValueMapper::<DebugApi, u64>::new(StorageKey::new(b"timestamp_first_whitelist"))
+                .set(PUBLIC_TIMESTAMP - FIRST_WHITELIST_TIMESTAMP_DELTA);
+            SingleValueMapper::<DebugApi, TokenIdentifier<DebugApi>>::new(StorageKey::new(
+                b"token_identifier",
+            ))
+            .set(TokenIdentifier::from_esdt_bytes(&EGG_ID));
+            SingleValueMapper::<DebugApi, u64>::new(StorageKey::new(b"token_nonce")).set(EGG_NONCE);
+            SingleValueMapper::<DebugApi, u64>::new(StorageKey::new(b"timestamp_sale_closed"))
+                .set(PUBLIC_TIMESTAMP + SALE_DURATION);
+
+            WhitelistMapper::<DebugApi, ManagedAddress<DebugApi>>::new(StorageKey::new(
+                b"first_whitelist_mapper",
+            ))
+            .add(&ManagedAddress::from_address(user_first_whitelisted));
+            WhitelistMapper::<DebugApi, ManagedAddress<DebugApi>>::new(StorageKey::new(
+                b"second_whitelist_mapper",
+            ))
+            .add(&ManagedAddress::from_address(user_second_whitelisted));
+        },
+    );
+}
+
+fn deploy_contract<ContractObjBuilder, Deploy>(
+    cf_builder: ContractObjBuilder,
+    deploy: Deploy,
+) -> ContractSetup<ContractObjBuilder>
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_mint::ContractObj<DebugApi>,
+    Deploy: FnOnce(public_sale_mint::ContractObj<DebugApi>, &Address, &Address),
 {
     DebugApi::dummy();
 
@@ -323,41 +1078,19 @@ where
         blockchain_wrapper.create_user_account(&egld_150),
     ];
 
+    let user_first_whitelisted = blockchain_wrapper.create_user_account(&egld_150);
+    let user_second_whitelisted = blockchain_wrapper.create_user_account(&egld_150);
+
     blockchain_wrapper
         .execute_tx(&owner_address, &cf_wrapper, &rust_zero, |sc| {
-            sc.init(
-                5,
-                ManagedVec::<DebugApi, BigUint<DebugApi>>::from(vec![
-                    big_uint_conv_num(10),
-                    big_uint_conv_num(9),
-                    big_uint_conv_num(8),
-                    big_uint_conv_num(7),
-                    big_uint_conv_num(6),
-                ]),
-                ManagedVec::<DebugApi, BigUint<DebugApi>>::from(vec![
-                    big_uint_conv_num(5),
-                    big_uint_conv_num(4),
-                    big_uint_conv_num(3),
-                    big_uint_conv_num(2),
-                    big_uint_conv_num(1),
-                ]),
-                PUBLIC_TIMESTAMP,
-                SECOND_WHITELIST_TIMESTAMP_DELTA,
-                FIRST_WHITELIST_TIMESTAMP_DELTA,
-                TokenIdentifier::from_esdt_bytes(&EGG_ID),
-                EGG_NONCE,
-                SALE_DURATION,
-            );
+            deploy(sc, &user_first_whitelisted, &user_second_whitelisted);
         })
         .assert_ok();
 
     blockchain_wrapper.set_block_timestamp(0);
     blockchain_wrapper.add_mandos_set_account(cf_wrapper.address_ref());
 
-    let user_first_whitelisted = blockchain_wrapper.create_user_account(&egld_150);
-    let user_second_whitelisted = blockchain_wrapper.create_user_account(&egld_150);
-
-    let mut contract_setup = ContractSetup {
+    return ContractSetup {
         blockchain_wrapper,
         owner_address: owner_address,
         contract_wrapper: cf_wrapper,
@@ -371,15 +1104,6 @@ where
         user_first_whitelisted,
         user_second_whitelisted,
     };
-
-    contract_setup
-        .add_to_first_whitelist(&contract_setup.user_first_whitelisted.clone())
-        .assert_ok();
-    contract_setup
-        .add_to_second_whitelist(&contract_setup.user_second_whitelisted.clone())
-        .assert_ok();
-
-    return contract_setup;
 }
 
 pub fn big_uint_conv_num(value: i64) -> BigUint<DebugApi> {
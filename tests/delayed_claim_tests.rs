@@ -0,0 +1,220 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::PublicSaleMint;
+
+const REVEALED_NONCE: u64 = 2;
+
+#[test]
+fn buy_records_pending_eggs() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(user, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(0u64));
+    assert_eq!(setup.get_pending_eggs(user), 2);
+    assert_eq!(setup.get_buyed_amount(user), 2);
+}
+
+#[test]
+fn claim_before_claim_timestamp() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup
+        .claim(user)
+        .assert_user_error(public_sale_mint::delayed_claim::ERR_CLAIM_NOT_OPEN);
+}
+
+#[test]
+fn claim_without_delayed_claim() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup
+        .claim(user)
+        .assert_user_error(public_sale_mint::delayed_claim::ERR_CLAIM_NOT_OPEN);
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(1u64));
+}
+
+#[test]
+fn claim_after_claim_timestamp() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(user, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(claim_timestamp);
+    setup.claim(user).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(2u64));
+    assert_eq!(setup.get_pending_eggs(user), 0);
+
+    setup
+        .claim(user)
+        .assert_user_error(public_sale_mint::delayed_claim::ERR_NOTHING_TO_CLAIM);
+}
+
+#[test]
+fn pending_eggs_cannot_be_sold_twice() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user1 = &setup.users[0].clone();
+    let user2 = &setup.users[1].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(1u64);
+    setup.buy(user1, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup
+        .buy(user2, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SOLD_OUT);
+}
+
+#[test]
+fn claim_revealed_nonce() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+
+    // the revealed eggs are sent to the contract before the reveal
+    let contract_address = setup.contract_wrapper.address_ref().clone();
+    let unrevealed_nonce = setup.egg_nonce;
+    setup.egg_nonce = REVEALED_NONCE;
+    setup.set_eggs(&contract_address, 10u64);
+    setup.egg_nonce = unrevealed_nonce;
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.reveal(SALE_ID, REVEALED_NONCE);
+        })
+        .assert_ok();
+
+    // the unrevealed eggs are sent back to the owner
+    assert_eq!(setup.get_eggs_balance(owner), rust_biguint!(10u64));
+
+    setup.egg_nonce = REVEALED_NONCE;
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(claim_timestamp);
+    setup.claim(user).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(1u64));
+}
+
+#[test]
+fn buy_more_pending_eggs_than_remaining() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(1u64);
+
+    setup
+        .buy(user, &rust_biguint!(6u64 * 5), 5)
+        .assert_user_error(public_sale_mint::ERR_SOLD_OUT);
+    assert_eq!(setup.get_pending_eggs(user), 0);
+
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+    assert_eq!(setup.get_pending_eggs(user), 1);
+}
+
+#[test]
+fn reveal_without_the_pending_eggs() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(user, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    let contract_address = setup.contract_wrapper.address_ref().clone();
+    setup.egg_nonce = REVEALED_NONCE;
+    setup.set_eggs(&contract_address, 1u64);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.reveal(SALE_ID, REVEALED_NONCE);
+        })
+        .assert_user_error(public_sale_mint::ERR_REVEAL_NOT_COVERED);
+
+    setup.set_eggs(&contract_address, 2u64);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.reveal(SALE_ID, REVEALED_NONCE);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn set_claim_timestamp_before_close() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration - 1;
+
+    setup
+        .set_claim_timestamp(claim_timestamp)
+        .assert_user_error(public_sale_mint::delayed_claim::ERR_CLAIM_BEFORE_CLOSE);
+}
+
+#[test]
+fn undelay_claim() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup.undelay_claim().assert_ok();
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(1u64));
+
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.buy(user, &rust_biguint!(9u64), 1).assert_ok();
+
+    assert_eq!(setup.get_pending_eggs(user), 1);
+    setup
+        .undelay_claim()
+        .assert_user_error(public_sale_mint::delayed_claim::ERR_CLAIM_EGGS_PENDING);
+}
@@ -1,6 +1,6 @@
 mod contract_setup;
 
-use contract_setup::setup_contract;
+use contract_setup::{setup_contract, SALE_ID};
 use elrond_wasm::contract_base::ContractBase;
 use elrond_wasm_debug::rust_biguint;
 use public_sale_mint::PublicSaleMint;
@@ -57,7 +57,7 @@ fn fill_bad_nonce() {
             |sc| {
                 let payment = sc.call_value().payment_as_tuple();
 
-                sc.fill_egg(payment.2, payment.0, payment.1);
+                sc.fill_egg(payment.2, payment.0, payment.1, SALE_ID);
             },
         )
         .assert_user_error(public_sale_mint::ERR_FILL_BAD_NONCE);
@@ -93,7 +93,7 @@ fn fill_bad_token() {
             |sc| {
                 let payment = sc.call_value().payment_as_tuple();
 
-                sc.fill_egg(payment.2, payment.0, payment.1);
+                sc.fill_egg(payment.2, payment.0, payment.1, SALE_ID);
             },
         )
         .assert_user_error(public_sale_mint::ERR_FILL_BAD_IDENTIFIER);
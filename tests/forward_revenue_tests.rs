@@ -0,0 +1,83 @@
+mod contract_setup;
+
+use contract_setup::setup_contract;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::revenue::RevenueModule;
+
+#[test]
+fn set_forward_revenue_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_forward_revenue(true);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn buy_forwards_to_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+
+    setup.set_forward_revenue(true).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(owner),
+        rust_biguint!(10u64)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(setup.contract_wrapper.address_ref()),
+        rust_biguint!(0u64)
+    );
+}
+
+#[test]
+fn buy_forwards_to_payees() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let treasury = &setup.users[2].clone();
+    let charity = &setup.users[3].clone();
+
+    setup.set_forward_revenue(true).assert_ok();
+    setup
+        .set_payees(&[(treasury, 8_000), (charity, 2_000)])
+        .assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(treasury),
+        rust_biguint!(150u64 + 8u64)
+    );
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(charity),
+        rust_biguint!(150u64 + 2u64)
+    );
+}
+
+#[test]
+fn buy_keeps_revenue_without_forwarding() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(setup.contract_wrapper.address_ref()),
+        rust_biguint!(10u64)
+    );
+}
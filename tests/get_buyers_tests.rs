@@ -1,6 +1,6 @@
 mod contract_setup;
 
-use contract_setup::setup_contract;
+use contract_setup::{setup_contract, SALE_ID};
 use elrond_wasm::types::ManagedAddress;
 use elrond_wasm_debug::{rust_biguint, DebugApi};
 use public_sale_mint::PublicSaleMint;
@@ -12,7 +12,7 @@ fn get_buyers_should_be_empty() {
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let buyers = sc.get_all_buyers();
+            let buyers = sc.get_all_buyers(SALE_ID);
             let mut buyers_iter = buyers.into_iter();
 
             assert_eq!(buyers_iter.next().is_none(), true);
@@ -32,7 +32,7 @@ fn get_buyers_should_contains_one_with_one() {
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let buyers = sc.get_all_buyers();
+            let buyers = sc.get_all_buyers(SALE_ID);
             let mut buyers_iter = buyers.into_iter();
 
             let next = buyers_iter.next();
@@ -60,7 +60,7 @@ fn get_buyers_should_contains_one_with_two() {
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let buyers = sc.get_all_buyers();
+            let buyers = sc.get_all_buyers(SALE_ID);
             let mut buyers_iter = buyers.into_iter();
 
             let next = buyers_iter.next();
@@ -89,7 +89,7 @@ fn get_buyers_should_contains_two_users() {
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let buyers = sc.get_all_buyers();
+            let buyers = sc.get_all_buyers(SALE_ID);
             let mut buyers_iter = buyers.into_iter();
 
             assert_eq!(
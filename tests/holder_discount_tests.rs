@@ -0,0 +1,132 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, HOLDER_SALE_TIMESTAMP_DELTA, SALE_ID};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::holders::HoldersModule;
+use public_sale_mint::PublicSaleMint;
+
+#[test]
+fn set_holder_discounts_not_sorted() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_holder_discounts(&[(5, 2_500), (1, 1_000)])
+        .assert_user_error(public_sale_mint::holders::ERR_HOLDER_DISCOUNTS_NOT_SORTED);
+
+    setup
+        .set_holder_discounts(&[(0, 1_000)])
+        .assert_user_error(public_sale_mint::holders::ERR_HOLDER_DISCOUNTS_NOT_SORTED);
+}
+
+#[test]
+fn set_holder_discounts_too_high() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_holder_discounts(&[(1, 10_001)])
+        .assert_user_error(public_sale_mint::holders::ERR_HOLDER_DISCOUNT_TOO_HIGH);
+}
+
+#[test]
+fn set_holder_discounts_as_user() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_holder_discounts(elrond_wasm::types::MultiValueEncoded::new());
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn get_holder_discount_uses_highest_threshold() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_holder_discounts(&[(1, 1_000), (5, 2_500)])
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_holder_discount(0), 0);
+            assert_eq!(sc.get_holder_discount(1), 1_000);
+            assert_eq!(sc.get_holder_discount(4), 1_000);
+            assert_eq!(sc.get_holder_discount(5), 2_500);
+            assert_eq!(sc.get_holder_discount(50), 2_500);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn holder_price_preview() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_holder_discounts(&[(1, 1_000), (5, 2_500)])
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_holder_price_preview(SALE_ID, 0, 1), 10u64);
+            assert_eq!(sc.get_holder_price_preview(SALE_ID, 1, 1), 9u64);
+            assert_eq!(sc.get_holder_price_preview(SALE_ID, 5, 2), 2u64 * 6u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn holder_buys_with_discount() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+
+    setup.set_holder_sale();
+    setup.set_holder_tokens(holder, &[1, 2, 3, 4, 5]);
+    setup
+        .set_holder_discounts(&[(1, 1_000), (5, 2_500)])
+        .assert_ok();
+    setup.fill_eggs(10u64);
+
+    setup.prove_holding(holder, &[1, 2, 3, 4, 5]).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(setup.public_timestamp - HOLDER_SALE_TIMESTAMP_DELTA);
+
+    // the reduced prices are replaced by the discount on the full prices
+    setup
+        .buy(holder, &rust_biguint!(5u64), 1)
+        .assert_user_error(public_sale_mint::ERR_BAD_AMOUNT_SENT);
+
+    // 10 - 25%, rounded down
+    setup.buy(holder, &rust_biguint!(7u64), 1).assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(holder), 1);
+}
+
+#[test]
+fn holder_buys_with_smaller_discount() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+
+    setup.set_holder_sale();
+    setup.set_holder_tokens(holder, &[1]);
+    setup
+        .set_holder_discounts(&[(1, 1_000), (5, 2_500)])
+        .assert_ok();
+    setup.fill_eggs(10u64);
+
+    setup.prove_holding(holder, &[1]).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(setup.public_timestamp - HOLDER_SALE_TIMESTAMP_DELTA);
+
+    setup
+        .buy(holder, &rust_biguint!(7u64), 1)
+        .assert_user_error(public_sale_mint::ERR_BAD_AMOUNT_SENT);
+    setup.buy(holder, &rust_biguint!(9u64), 1).assert_ok();
+}
@@ -0,0 +1,184 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, HOLDER_SALE_TIMESTAMP_DELTA, HOLDER_TOKEN_ID, SALE_ID};
+use elrond_wasm::types::TokenIdentifier;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::holders::HoldersModule;
+use public_sale_mint::phase::SalePhase;
+use public_sale_mint::PublicSaleMint;
+
+#[test]
+fn prove_holding_gives_back_the_tokens() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+
+    setup.set_holder_sale();
+    setup.set_holder_tokens(holder, &[1, 2]);
+
+    setup.prove_holding(holder, &[1, 2]).assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(holder, &HOLDER_TOKEN_ID, 1),
+        rust_biguint!(1u64)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(holder, &HOLDER_TOKEN_ID, 2),
+        rust_biguint!(1u64)
+    );
+}
+
+#[test]
+fn prove_holding_with_other_token() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+
+    setup.set_holder_sale();
+    setup.set_eggs(holder, 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_esdt_transfer(
+            holder,
+            &setup.contract_wrapper,
+            &setup.egg_id,
+            setup.egg_nonce,
+            &rust_biguint!(1u64),
+            |sc| {
+                sc.prove_holding(SALE_ID);
+            },
+        )
+        .assert_user_error(public_sale_mint::holders::ERR_HOLDER_BAD_TOKEN);
+}
+
+#[test]
+fn holder_buys_during_holder_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+    let user = &setup.users[1].clone();
+
+    setup.set_holder_sale();
+    setup.set_holder_tokens(holder, &[1]);
+    setup.fill_eggs(10u64);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(setup.public_timestamp - HOLDER_SALE_TIMESTAMP_DELTA);
+
+    // not proven yet
+    setup
+        .buy(holder, &rust_biguint!(5u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_OPEN);
+
+    setup.prove_holding(holder, &[1]).assert_ok();
+
+    // holders get the reduced price
+    setup
+        .buy(holder, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::ERR_BAD_AMOUNT_SENT);
+    setup.buy(holder, &rust_biguint!(5u64), 1).assert_ok();
+
+    setup
+        .buy(user, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_OPEN);
+}
+
+#[test]
+fn holder_cannot_buy_before_holder_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+
+    setup.set_holder_sale();
+    setup.set_holder_tokens(holder, &[1]);
+    setup.fill_eggs(10u64);
+    setup.prove_holding(holder, &[1]).assert_ok();
+
+    setup.set_sale_as_not_started();
+
+    setup
+        .buy(holder, &rust_biguint!(5u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_OPEN);
+}
+
+#[test]
+fn prove_holding_with_token_of_another_wallet() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+    let other = &setup.users[1].clone();
+
+    setup.set_holder_sale();
+    setup.set_holder_tokens(holder, &[1]);
+    setup.prove_holding(holder, &[1]).assert_ok();
+
+    // the token is handed over to another wallet
+    setup.set_holder_tokens(other, &[1]);
+    setup
+        .prove_holding(other, &[1])
+        .assert_user_error(public_sale_mint::holders::ERR_HOLDER_TOKEN_ALREADY_USED);
+
+    // the first holder can still prove again with it
+    setup.prove_holding(holder, &[1]).assert_ok();
+}
+
+#[test]
+fn set_holder_sale_after_public_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let public_timestamp = setup.public_timestamp;
+
+    setup
+        .set_holder_sale_at(public_timestamp)
+        .assert_user_error(public_sale_mint::holders::ERR_HOLDER_SALE_AFTER_PUBLIC);
+    setup.set_holder_sale_at(public_timestamp - 1).assert_ok();
+}
+
+#[test]
+fn set_holder_sale_of_unknown_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.set_holder_sale(
+                    SALE_ID + 1,
+                    TokenIdentifier::from_esdt_bytes(&HOLDER_TOKEN_ID),
+                    10,
+                );
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_FOUND);
+}
+
+#[test]
+fn holder_purchases_consume_the_holder_allocation() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let holder = &setup.users[0].clone();
+    let first_whitelist_timestamp = setup.first_whitelist_timestamp;
+
+    // the holder sale opens before the first whitelist
+    setup
+        .set_holder_sale_at(first_whitelist_timestamp - 20)
+        .assert_ok();
+    setup.set_holder_tokens(holder, &[1]);
+    setup.fill_eggs(10u64);
+    setup.prove_holding(holder, &[1]).assert_ok();
+    setup.set_phase_allocation(SalePhase::Holder, 1).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(first_whitelist_timestamp - 10);
+    assert!(setup.get_current_phase() == SalePhase::Holder);
+
+    setup
+        .buy(holder, &rust_biguint!(4u64 + 4u64), 2)
+        .assert_user_error(public_sale_mint::allocation::ERR_ALLOCATION_EXCEEDED);
+    setup.buy(holder, &rust_biguint!(5u64), 1).assert_ok();
+
+    assert_eq!(setup.get_remaining_allocation(SalePhase::Holder), Some(0));
+}
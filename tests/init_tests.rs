@@ -1,44 +1,63 @@
-use elrond_wasm::types::{ManagedVec, TokenIdentifier};
+use elrond_wasm::elrond_codec::multi_types::OptionalValue;
+use elrond_wasm::types::ManagedVec;
 use elrond_wasm_debug::{
     testing_framework::BlockchainStateWrapper,
     tx_mock::{TxContextRef, TxResult},
 };
-use public_sale_mint::{whitelist::WhitelistModule, PublicSaleMint};
+use public_sale_mint::{
+    config::{SaleConfig, SaleSchedule},
+    reserve::ReserveModule,
+    whitelist::{BuyerTier, WhitelistModule},
+    PublicSaleMint,
+};
 mod contract_setup;
 
+use contract_setup::SALE_ID;
+
 #[test]
 fn init() {
     warmup_init(|sc| {
-        sc.init(
-            3,
-            ManagedVec::from(vec![1u64, 5u64, 10u64]),
-            ManagedVec::from(vec![1u64, 4u64, 9u64]),
-            50,
-            10,
-            20,
-            TokenIdentifier::from_esdt_bytes(b"TOKEN"),
-            3,
-            10,
-        );
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 3,
+            max_per_wallet_second_whitelist: 3,
+            max_per_wallet_public: 3,
+            price_per_egg: ManagedVec::from(vec![1u64, 5u64, 10u64]),
+            reduced_price_per_egg: ManagedVec::from(vec![1u64, 4u64, 9u64]),
+            timestamp_public_sale: 50,
+            second_whitelist_delta: 10,
+            first_whitelist_delta: 20,
+            sale_duration: 10,
+            reserved_supply: 0,
+            schedule: None,
+        }));
 
-        assert_eq!(sc.max_per_wallet().get(), 3);
-        assert_eq!(sc.price_per_egg().len(), 3);
-        assert_eq!(sc.price_per_egg().get(1), 1u64);
-        assert_eq!(sc.price_per_egg().get(2), 5u64);
-        assert_eq!(sc.price_per_egg().get(3), 10u64);
-        assert_eq!(sc.reduced_price_per_egg().len(), 3);
-        assert_eq!(sc.reduced_price_per_egg().get(1), 1u64);
-        assert_eq!(sc.reduced_price_per_egg().get(2), 4u64);
-        assert_eq!(sc.reduced_price_per_egg().get(3), 9u64);
-        assert_eq!(sc.timestamp_public_sale().get(), 50);
-        assert_eq!(sc.timestamp_second_whitelist().get(), 40);
-        assert_eq!(sc.timestamp_first_whitelist().get(), 30);
+        assert_eq!(sc.last_sale_id().get(), SALE_ID);
+        assert_eq!(
+            sc.max_per_wallet(SALE_ID, &BuyerTier::FirstWhitelist).get(),
+            3
+        );
         assert_eq!(
-            sc.token_identifier().get(),
-            TokenIdentifier::from_esdt_bytes(b"TOKEN")
+            sc.max_per_wallet(SALE_ID, &BuyerTier::SecondWhitelist)
+                .get(),
+            3
         );
-        assert_eq!(sc.token_nonce().get(), 3);
-        assert_eq!(sc.timestamp_sale_closed().get(), 60);
+        assert_eq!(sc.max_per_wallet(SALE_ID, &BuyerTier::Public).get(), 3);
+        assert_eq!(sc.price_per_egg(SALE_ID).len(), 3);
+        assert_eq!(sc.price_per_egg(SALE_ID).get(1), 1u64);
+        assert_eq!(sc.price_per_egg(SALE_ID).get(2), 5u64);
+        assert_eq!(sc.price_per_egg(SALE_ID).get(3), 10u64);
+        assert_eq!(sc.reduced_price_per_egg(SALE_ID).len(), 3);
+        assert_eq!(sc.reduced_price_per_egg(SALE_ID).get(1), 1u64);
+        assert_eq!(sc.reduced_price_per_egg(SALE_ID).get(2), 4u64);
+        assert_eq!(sc.reduced_price_per_egg(SALE_ID).get(3), 9u64);
+        assert_eq!(sc.timestamp_public_sale(SALE_ID).get(), 50);
+        assert_eq!(sc.timestamp_second_whitelist(SALE_ID).get(), 40);
+        assert_eq!(sc.timestamp_first_whitelist(SALE_ID).get(), 30);
+        // the egg collection is issued after the deployment
+        assert!(sc.token_identifier(SALE_ID).is_empty());
+        assert_eq!(sc.token_nonce(SALE_ID).get(), 0);
+        assert_eq!(sc.timestamp_sale_closed(SALE_ID).get(), 60);
+        assert_eq!(sc.reserved_supply(SALE_ID).get(), 0);
     })
     .assert_ok();
 }
@@ -46,17 +65,19 @@ fn init() {
 #[test]
 fn init_second_wl_lesser_then_first() {
     warmup_init(|sc| {
-        sc.init(
-            5,
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
-            0,
-            5,
-            0,
-            TokenIdentifier::from_esdt_bytes(b"TOKEN"),
-            3,
-            0,
-        );
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 5,
+            max_per_wallet_public: 5,
+            price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
+            reduced_price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
+            timestamp_public_sale: 0,
+            second_whitelist_delta: 5,
+            first_whitelist_delta: 0,
+            sale_duration: 0,
+            reserved_supply: 0,
+            schedule: None,
+        }));
     })
     .assert_user_error(public_sale_mint::ERR_INIT_SECOND_WL_LESSER_THEN_FIRST);
 }
@@ -64,17 +85,19 @@ fn init_second_wl_lesser_then_first() {
 #[test]
 fn full_prices_length_different_from_max_per_wallet() {
     warmup_init(|sc| {
-        sc.init(
-            5,
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64]),
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
-            0,
-            0,
-            0,
-            TokenIdentifier::from_esdt_bytes(b"TOKEN"),
-            3,
-            0,
-        );
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 5,
+            max_per_wallet_public: 5,
+            price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64]),
+            reduced_price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
+            timestamp_public_sale: 0,
+            second_whitelist_delta: 0,
+            first_whitelist_delta: 0,
+            sale_duration: 0,
+            reserved_supply: 0,
+            schedule: None,
+        }));
     })
     .assert_user_error(public_sale_mint::ERR_INIT_PRICE_PER_EGG_DIFF);
 }
@@ -82,17 +105,19 @@ fn full_prices_length_different_from_max_per_wallet() {
 #[test]
 fn reduced_prices_length_different_from_max_per_wallet() {
     warmup_init(|sc| {
-        sc.init(
-            5,
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64]),
-            0,
-            0,
-            0,
-            TokenIdentifier::from_esdt_bytes(b"TOKEN"),
-            3,
-            0,
-        );
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 5,
+            max_per_wallet_public: 5,
+            price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
+            reduced_price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64]),
+            timestamp_public_sale: 0,
+            second_whitelist_delta: 0,
+            first_whitelist_delta: 0,
+            sale_duration: 0,
+            reserved_supply: 0,
+            schedule: None,
+        }));
     })
     .assert_user_error(public_sale_mint::ERR_INIT_REDUCED_PRICE_PER_EGG_DIFF);
 }
@@ -100,17 +125,19 @@ fn reduced_prices_length_different_from_max_per_wallet() {
 #[test]
 fn full_price_equals_0() {
     warmup_init(|sc| {
-        sc.init(
-            5,
-            ManagedVec::new(),
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
-            0,
-            0,
-            0,
-            TokenIdentifier::from_esdt_bytes(b"TOKEN"),
-            3,
-            0,
-        );
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 5,
+            max_per_wallet_public: 5,
+            price_per_egg: ManagedVec::new(),
+            reduced_price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64, 5u64]),
+            timestamp_public_sale: 0,
+            second_whitelist_delta: 0,
+            first_whitelist_delta: 0,
+            sale_duration: 0,
+            reserved_supply: 0,
+            schedule: None,
+        }));
     })
     .assert_user_error(public_sale_mint::ERR_INIT_PRICE_PER_EGG_ZERO);
 }
@@ -118,21 +145,168 @@ fn full_price_equals_0() {
 #[test]
 fn reduced_price_equals_0() {
     warmup_init(|sc| {
-        sc.init(
-            5,
-            ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64]),
-            ManagedVec::new(),
-            0,
-            0,
-            0,
-            TokenIdentifier::from_esdt_bytes(b"TOKEN"),
-            3,
-            0,
-        );
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 5,
+            max_per_wallet_public: 5,
+            price_per_egg: ManagedVec::from(vec![1u64, 2u64, 3u64, 4u64]),
+            reduced_price_per_egg: ManagedVec::new(),
+            timestamp_public_sale: 0,
+            second_whitelist_delta: 0,
+            first_whitelist_delta: 0,
+            sale_duration: 0,
+            reserved_supply: 0,
+            schedule: None,
+        }));
     })
     .assert_user_error(public_sale_mint::ERR_INIT_REDUCED_PRICE_PER_EGG_ZERO);
 }
 
+#[test]
+fn init_with_different_max_per_wallet() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 5,
+            max_per_wallet_second_whitelist: 2,
+            max_per_wallet_public: 3,
+            price_per_egg: ManagedVec::from(vec![5u64, 4u64, 3u64, 2u64, 1u64]),
+            reduced_price_per_egg: ManagedVec::from(vec![2u64, 1u64]),
+            timestamp_public_sale: 50,
+            second_whitelist_delta: 10,
+            first_whitelist_delta: 20,
+            sale_duration: 10,
+            reserved_supply: 0,
+            schedule: None,
+        }));
+
+        assert_eq!(
+            sc.max_per_wallet(SALE_ID, &BuyerTier::FirstWhitelist).get(),
+            5
+        );
+        assert_eq!(
+            sc.max_per_wallet(SALE_ID, &BuyerTier::SecondWhitelist)
+                .get(),
+            2
+        );
+        assert_eq!(sc.max_per_wallet(SALE_ID, &BuyerTier::Public).get(), 3);
+    })
+    .assert_ok();
+}
+
+#[test]
+fn full_prices_length_different_from_public_max_per_wallet() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 2,
+            max_per_wallet_second_whitelist: 2,
+            max_per_wallet_public: 3,
+            price_per_egg: ManagedVec::from(vec![1u64, 2u64]),
+            reduced_price_per_egg: ManagedVec::from(vec![1u64, 2u64]),
+            timestamp_public_sale: 0,
+            second_whitelist_delta: 0,
+            first_whitelist_delta: 0,
+            sale_duration: 0,
+            reserved_supply: 0,
+            schedule: None,
+        }));
+    })
+    .assert_user_error(public_sale_mint::ERR_INIT_PRICE_PER_EGG_DIFF);
+}
+
+#[test]
+fn get_sale_config() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(valid_config()));
+
+        let config = sc.get_sale_config(SALE_ID);
+        assert!(
+            config
+                == SaleConfig {
+                    schedule: Some(SaleSchedule {
+                        timestamp_first_whitelist: 30,
+                        timestamp_second_whitelist: 40,
+                        timestamp_public_sale: 50,
+                        timestamp_sale_closed: 60,
+                    }),
+                    ..valid_config()
+                }
+        );
+    })
+    .assert_ok();
+}
+
+#[test]
+fn init_without_eggs_per_wallet() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            max_per_wallet_first_whitelist: 0,
+            max_per_wallet_second_whitelist: 0,
+            max_per_wallet_public: 0,
+            price_per_egg: ManagedVec::new(),
+            reduced_price_per_egg: ManagedVec::new(),
+            ..valid_config()
+        }));
+    })
+    .assert_user_error(public_sale_mint::ERR_INIT_ZERO_SUPPLY);
+}
+
+#[test]
+fn init_first_wl_before_timestamp_zero() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            timestamp_public_sale: 15,
+            ..valid_config()
+        }));
+    })
+    .assert_user_error(public_sale_mint::ERR_INIT_FIRST_WL_UNDERFLOW);
+}
+
+#[test]
+fn init_without_sale_duration() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            sale_duration: 0,
+            ..valid_config()
+        }));
+    })
+    .assert_user_error(public_sale_mint::ERR_INIT_CLOSE_BEFORE_OPEN);
+}
+
+#[test]
+fn init_close_overflow() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::Some(SaleConfig {
+            timestamp_public_sale: u64::MAX - 5,
+            ..valid_config()
+        }));
+    })
+    .assert_user_error(public_sale_mint::ERR_INIT_CLOSE_OVERFLOW);
+}
+
+#[test]
+fn init_without_config() {
+    warmup_init(|sc| {
+        sc.init(OptionalValue::None);
+    })
+    .assert_user_error(public_sale_mint::ERR_INIT_CONFIG_MISSING);
+}
+
+fn valid_config() -> SaleConfig<TxContextRef> {
+    return SaleConfig {
+        max_per_wallet_first_whitelist: 3,
+        max_per_wallet_second_whitelist: 2,
+        max_per_wallet_public: 3,
+        price_per_egg: ManagedVec::from(vec![1u64, 5u64, 10u64]),
+        reduced_price_per_egg: ManagedVec::from(vec![1u64, 4u64]),
+        timestamp_public_sale: 50,
+        second_whitelist_delta: 10,
+        first_whitelist_delta: 20,
+        sale_duration: 10,
+        reserved_supply: 2,
+        schedule: None,
+    };
+}
+
 pub fn warmup_init(tx_fn: fn(public_sale_mint::ContractObj<TxContextRef>)) -> TxResult {
     let rust_zero = elrond_wasm_debug::rust_biguint!(0u64);
     let mut blockchain_wrapper = BlockchainStateWrapper::new();
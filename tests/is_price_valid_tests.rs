@@ -1,19 +1,21 @@
 mod contract_setup;
 
-use contract_setup::{big_uint_conv_num, setup_contract};
-use elrond_wasm::types::BigUint;
+use contract_setup::{big_uint_conv_num, setup_contract, SALE_ID};
+use elrond_wasm::types::{BigUint, ManagedAddress};
 use elrond_wasm_debug::DebugApi;
-use public_sale_mint::PublicSaleMint;
+use public_sale_mint::{whitelist::BuyerTier, PublicSaleMint};
 
 #[test]
 fn buy_one_from_zero() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let price = sc.price_per_egg().get(1);
-            let is_valid = sc.is_price_valid(price, 0, sc.price_per_egg(), 1);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let price = sc.price_per_egg(SALE_ID).get(1);
+            let is_valid = sc.is_price_valid(price, SALE_ID, &buyer, 0, 1, &[]);
             assert_eq!(is_valid, true);
         })
         .assert_ok();
@@ -24,13 +26,15 @@ fn buy_two_from_zero() {
     DebugApi::dummy();
 
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let p2 = sc.price_per_egg().get(2);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let p2 = sc.price_per_egg(SALE_ID).get(2);
 
-            let is_valid = sc.is_price_valid(&p2 + &p2, 0, sc.price_per_egg(), 2);
+            let is_valid = sc.is_price_valid(&p2 + &p2, SALE_ID, &buyer, 0, 2, &[]);
             assert_eq!(is_valid, true);
         })
         .assert_ok();
@@ -39,13 +43,15 @@ fn buy_two_from_zero() {
 #[test]
 fn buy_two_from_one() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let p3 = sc.price_per_egg().get(3);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let p3 = sc.price_per_egg(SALE_ID).get(3);
 
-            let is_valid = sc.is_price_valid(&p3 + &p3, 1, sc.price_per_egg(), 2);
+            let is_valid = sc.is_price_valid(&p3 + &p3, SALE_ID, &buyer, 1, 2, &[]);
             assert_eq!(is_valid, true);
         })
         .assert_ok();
@@ -54,13 +60,15 @@ fn buy_two_from_one() {
 #[test]
 fn buy_bad_price_from_one() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let p1 = sc.price_per_egg().get(1);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let p1 = sc.price_per_egg(SALE_ID).get(1);
 
-            let is_valid = sc.is_price_valid(&p1 + &p1, 1, sc.price_per_egg(), 2);
+            let is_valid = sc.is_price_valid(&p1 + &p1, SALE_ID, &buyer, 1, 2, &[]);
             assert_eq!(is_valid, false);
         })
         .assert_ok();
@@ -69,14 +77,16 @@ fn buy_bad_price_from_one() {
 #[test]
 fn buy_with_price_not_listed() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let p1 = sc.price_per_egg().get(2);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let p1 = sc.price_per_egg(SALE_ID).get(2);
             let delta = big_uint_conv_num(1);
 
-            let _ = sc.is_price_valid(p1 + delta, 1, sc.price_per_egg(), 1);
+            let _ = sc.is_price_valid(p1 + delta, SALE_ID, &buyer, 1, 1, &[]);
         })
         .assert_ok();
 }
@@ -84,13 +94,15 @@ fn buy_with_price_not_listed() {
 #[test]
 fn send_too_much() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
+            let buyer = ManagedAddress::from_address(&buyer);
             let p1 = big_uint_conv_num(999999);
 
-            let _ = sc.is_price_valid(p1, 1, sc.price_per_egg(), 1);
+            let _ = sc.is_price_valid(p1, SALE_ID, &buyer, 1, 1, &[]);
         })
         .assert_ok();
 }
@@ -98,17 +110,21 @@ fn send_too_much() {
 #[test]
 fn buy_to_max_wallet_from_zero() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let price = sc.price_per_egg().get(5);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let price = sc.price_per_egg(SALE_ID).get(5);
 
             let is_valid = sc.is_price_valid(
                 &price + &price + &price + &price + &price,
+                SALE_ID,
+                &buyer,
                 0,
-                sc.price_per_egg(),
                 5,
+                &[],
             );
             assert_eq!(is_valid, true);
         })
@@ -118,18 +134,25 @@ fn buy_to_max_wallet_from_zero() {
 #[test]
 fn buy_to_max_wallet_from_max_wallet() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
+            let buyer = ManagedAddress::from_address(&buyer);
             let mut price_sum = BigUint::<DebugApi>::zero();
 
-            for price in sc.price_per_egg().iter() {
+            for price in sc.price_per_egg(SALE_ID).iter() {
                 price_sum += price;
             }
 
-            let is_valid =
-                sc.is_price_valid(price_sum, sc.max_per_wallet().get(), sc.price_per_egg(), 5);
+            let is_valid = sc.is_price_valid(
+                price_sum,
+                sc.max_per_wallet(SALE_ID, SALE_ID, &buyer, &BuyerTier::Public)
+                    .get(),
+                5,
+                &[],
+            );
             assert_eq!(is_valid, false);
         })
         .assert_ok();
@@ -138,14 +161,67 @@ fn buy_to_max_wallet_from_max_wallet() {
 #[test]
 fn buy_one_from_max_wallet() {
     let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
 
     setup
         .blockchain_wrapper
         .execute_query(&setup.contract_wrapper, |sc| {
-            let price = sc.price_per_egg().get(1);
+            let buyer = ManagedAddress::from_address(&buyer);
+            let price = sc.price_per_egg(SALE_ID).get(1);
+
+            let is_valid = sc.is_price_valid(
+                price,
+                sc.max_per_wallet(SALE_ID, SALE_ID, &buyer, &BuyerTier::Public)
+                    .get(),
+                1,
+                &[],
+            );
+            assert_eq!(is_valid, false);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn price_with_discounts() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let buyer = ManagedAddress::from_address(&buyer);
+
+            // 10 minus 10% then minus 50%
+            let is_valid =
+                sc.is_price_valid(big_uint_conv_num(4), SALE_ID, &buyer, 0, 1, &[1_000, 5_000]);
+            assert_eq!(is_valid, true);
 
             let is_valid =
-                sc.is_price_valid(price, sc.max_per_wallet().get(), sc.price_per_egg(), 1);
+                sc.is_price_valid(big_uint_conv_num(10), SALE_ID, &buyer, 0, 1, &[1_000]);
+            assert_eq!(is_valid, false);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn price_of_an_allocation_override() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = setup.users[0].clone();
+
+    setup
+        .set_allocation_override(&buyer, 10, Some(3))
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let buyer = ManagedAddress::from_address(&buyer);
+
+            let is_valid = sc.is_price_valid(big_uint_conv_num(21), SALE_ID, &buyer, 5, 7, &[]);
+            assert_eq!(is_valid, true);
+
+            let price = sc.price_per_egg(SALE_ID).get(1);
+            let is_valid = sc.is_price_valid(price, SALE_ID, &buyer, 0, 1, &[]);
             assert_eq!(is_valid, false);
         })
         .assert_ok();
@@ -0,0 +1,101 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::{whitelist::BuyerTier, PublicSaleMint};
+
+#[test]
+fn max_per_wallet_depends_on_tier() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+    let public_user = &setup.users[0].clone();
+    let first_whitelisted = &setup.user_first_whitelisted.clone();
+    let second_whitelisted = &setup.user_second_whitelisted.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.max_per_wallet(SALE_ID, &BuyerTier::SecondWhitelist)
+                .set(4);
+            sc.max_per_wallet(SALE_ID, &BuyerTier::Public).set(2);
+        })
+        .assert_ok();
+
+    assert_eq!(setup.get_max_per_wallet(first_whitelisted), 5);
+    assert_eq!(setup.get_max_per_wallet(second_whitelisted), 4);
+    assert_eq!(setup.get_max_per_wallet(public_user), 2);
+}
+
+#[test]
+fn buy_more_than_public_max_per_wallet() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.max_per_wallet(SALE_ID, &BuyerTier::Public).set(2);
+        })
+        .assert_ok();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy(user, &rust_biguint!(8u64 * 3), 3)
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+    setup.buy(user, &rust_biguint!(9u64 * 2), 2).assert_ok();
+    setup
+        .buy(user, &rust_biguint!(8u64), 1)
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+
+    assert_eq!(setup.get_buyed_amount(user), 2);
+}
+
+#[test]
+fn first_whitelisted_keeps_its_max_per_wallet_in_public() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+    let user = &setup.user_first_whitelisted.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.max_per_wallet(SALE_ID, &BuyerTier::Public).set(2);
+        })
+        .assert_ok();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup.buy(user, &rust_biguint!(8u64 * 3), 3).assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(user), 3);
+}
+
+#[test]
+fn price_quote() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let second_whitelisted = &setup.user_second_whitelisted.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let public_user = ManagedAddress::from_address(user);
+            let reduced_user = ManagedAddress::from_address(second_whitelisted);
+
+            assert_eq!(sc.get_price_quote(SALE_ID, &public_user, 2), 9u64 * 2);
+            assert_eq!(sc.get_price_quote(SALE_ID, &reduced_user, 2), 4u64 * 2);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            sc.get_price_quote(SALE_ID, &ManagedAddress::from_address(user), 6);
+        })
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+}
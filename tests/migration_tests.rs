@@ -0,0 +1,218 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, setup_legacy_contract, SALE_ID};
+use elrond_wasm::types::{ManagedAddress, MultiValueEncoded, TokenIdentifier};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::{
+    migration::{MigrationModule, STORAGE_VERSION},
+    whitelist::WhitelistModule,
+    PublicSaleMint,
+};
+
+#[test]
+fn deploy_with_current_storage_version() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.storage_version().get(), STORAGE_VERSION);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn upgrade_numbers_the_legacy_sale() {
+    let mut setup = setup_legacy_contract(public_sale_mint::contract_obj);
+    let first_buyer = &setup.users[0].clone();
+    let second_buyer = &setup.users[1].clone();
+
+    setup.set_legacy_purchases(&[(first_buyer, 3), (second_buyer, 1)]);
+
+    setup.upgrade().assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(first_buyer), 3);
+    assert_eq!(setup.get_buyed_amount(second_buyer), 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.storage_version().get(), STORAGE_VERSION);
+            assert_eq!(sc.last_sale_id().get(), SALE_ID);
+            assert!(!sc.has_unnumbered_sale());
+
+            assert_eq!(sc.legacy_already_bought(SALE_ID).len(), 0);
+            assert_eq!(sc.unnumbered_already_bought().len(), 0);
+            assert_eq!(sc.buyers(SALE_ID).len(), 2);
+
+            // the upgrade arguments don't replace the legacy sale
+            assert_eq!(sc.price_per_egg(SALE_ID).len(), 5);
+            assert_eq!(sc.price_per_egg(SALE_ID).get(1), 10u64);
+            assert_eq!(sc.reduced_price_per_egg(SALE_ID).get(5), 1u64);
+            assert_eq!(
+                sc.token_identifier(SALE_ID).get(),
+                TokenIdentifier::from_esdt_bytes(&contract_setup::EGG_ID)
+            );
+            assert_eq!(sc.token_nonce(SALE_ID).get(), contract_setup::EGG_NONCE);
+            assert_eq!(
+                sc.timestamp_public_sale(SALE_ID).get(),
+                contract_setup::PUBLIC_TIMESTAMP
+            );
+            assert_eq!(
+                sc.timestamp_sale_closed(SALE_ID).get(),
+                contract_setup::PUBLIC_TIMESTAMP + contract_setup::SALE_DURATION
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn migrated_buyers_keep_their_max_per_wallet() {
+    let mut setup = setup_legacy_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+
+    setup.set_legacy_purchases(&[(buyer, 4)]);
+    setup.upgrade().assert_ok();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy(buyer, &rust_biguint!(6u64 + 6u64), 2)
+        .assert_user_error(public_sale_mint::ERR_MAX_PER_WALLET_EXCEEDED);
+    setup.buy(buyer, &rust_biguint!(6u64), 1).assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(buyer), 5);
+}
+
+#[test]
+fn migrate_legacy_whitelisted() {
+    let mut setup = setup_legacy_contract(public_sale_mint::contract_obj);
+    let first_whitelisted = setup.user_first_whitelisted.clone();
+    let second_whitelisted = setup.user_second_whitelisted.clone();
+    let user = setup.users[0].clone();
+
+    setup.upgrade().assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                let mut addresses = MultiValueEncoded::new();
+                addresses.push(ManagedAddress::from_address(&first_whitelisted));
+                addresses.push(ManagedAddress::from_address(&second_whitelisted));
+                addresses.push(ManagedAddress::from_address(&user));
+
+                sc.migrate_whitelisted(addresses);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let first_whitelisted = ManagedAddress::from_address(&first_whitelisted);
+            let second_whitelisted = ManagedAddress::from_address(&second_whitelisted);
+            let user = ManagedAddress::from_address(&user);
+
+            assert!(sc.check_contains_first(SALE_ID, &first_whitelisted));
+            assert!(sc.check_contains_second(SALE_ID, &second_whitelisted));
+            assert!(!sc.check_contains_first(SALE_ID, &user));
+            assert!(!sc.check_contains_second(SALE_ID, &user));
+
+            assert!(!sc.unnumbered_first_whitelist().contains(&first_whitelisted));
+            assert!(!sc
+                .unnumbered_second_whitelist()
+                .contains(&second_whitelisted));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn migrate_whitelisted_while_not_admin() {
+    let mut setup = setup_legacy_contract(public_sale_mint::contract_obj);
+
+    setup.upgrade().assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.users[0],
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.migrate_whitelisted(MultiValueEncoded::new());
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn upgrade_twice() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup.upgrade().assert_ok();
+    setup.upgrade().assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(buyer), 1);
+
+    let buyer_address = buyer.clone();
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let record = sc
+                .get_buyer_record(SALE_ID, &ManagedAddress::from_address(&buyer_address))
+                .unwrap();
+
+            assert_eq!(record.bought, 1);
+            assert_eq!(record.spent, 10u64);
+            assert_eq!(record.last_purchase, contract_setup::PUBLIC_TIMESTAMP);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn migrate_while_not_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.users[0],
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.migrate();
+            },
+        )
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn upgrade_from_more_recent_storage() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.storage_version().set(STORAGE_VERSION + 1);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .upgrade()
+        .assert_user_error(public_sale_mint::migration::ERR_STORAGE_VERSION_UNKNOWN);
+}
@@ -0,0 +1,266 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, EGG_ID, SALE_ID};
+use elrond_wasm::types::{BigUint, EsdtLocalRole, ManagedBuffer, MultiValueEncoded};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::mint::MintModule;
+use public_sale_mint::PublicSaleMint;
+
+#[test]
+fn enable_mint_on_demand_without_role() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.enable_mint_on_demand(SALE_ID, 10);
+            },
+        )
+        .assert_user_error(public_sale_mint::mint::ERR_MINT_MISSING_ROLE);
+}
+
+#[test]
+fn enable_mint_on_demand_without_egg_nonce() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.token_nonce(SALE_ID).set(0);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .enable_mint_on_demand(10)
+        .assert_user_error(public_sale_mint::ERR_EGG_NONCE_NOT_CREATED);
+}
+
+#[test]
+fn enable_mint_on_demand_as_user() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.enable_mint_on_demand(SALE_ID, 10);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn remaining_nft_is_max_supply_minus_minted() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.enable_mint_on_demand(10).assert_ok();
+    setup.open_public_sale();
+
+    setup.buy(user, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(2u64));
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.minted_amount(SALE_ID).get(), 2);
+            assert_eq!(sc.get_remaining_nft(SALE_ID), 8u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_when_max_supply_reached() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let first_user = &setup.users[0].clone();
+    let second_user = &setup.users[1].clone();
+
+    setup.enable_mint_on_demand(1).assert_ok();
+    setup.open_public_sale();
+
+    setup.buy(first_user, &rust_biguint!(10u64), 1).assert_ok();
+    setup
+        .buy(second_user, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SOLD_OUT);
+}
+
+#[test]
+fn max_supply_below_minted() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.enable_mint_on_demand(10).assert_ok();
+    setup.open_public_sale();
+
+    setup.buy(user, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    setup
+        .enable_mint_on_demand(1)
+        .assert_user_error(public_sale_mint::mint::ERR_MAX_SUPPLY_BELOW_MINTED);
+    setup.enable_mint_on_demand(2).assert_ok();
+}
+
+#[test]
+fn sell_created_eggs_before_minting() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let first_user = &setup.users[0].clone();
+    let second_user = &setup.users[1].clone();
+
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.contract_wrapper.address_ref(),
+        &EGG_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity],
+    );
+
+    let mut nonce = 0u64;
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.token_nonce(SALE_ID).set(0);
+
+                nonce = sc.create_egg_nonce(
+                    SALE_ID,
+                    4,
+                    ManagedBuffer::from(b"Egg"),
+                    BigUint::zero(),
+                    ManagedBuffer::new(),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_ok();
+
+    setup.enable_mint_on_demand(10).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            // the created eggs are kept for sale
+            assert_eq!(sc.minted_amount(SALE_ID).get(), 4);
+            assert_eq!(sc.get_remaining_nft(SALE_ID), 10u64);
+        })
+        .assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_esdt_balance(
+            setup.contract_wrapper.address_ref(),
+            &EGG_ID,
+            nonce
+        ),
+        rust_biguint!(4u64)
+    );
+
+    setup.open_public_sale();
+
+    // the eggs held are sold before minting more
+    setup
+        .buy(first_user, &rust_biguint!(9u64 + 9u64), 2)
+        .assert_ok();
+    setup
+        .buy(second_user, &rust_biguint!(8u64 + 8u64 + 8u64), 3)
+        .assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(first_user, &EGG_ID, nonce),
+        rust_biguint!(2u64)
+    );
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(second_user, &EGG_ID, nonce),
+        rust_biguint!(3u64)
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.minted_amount(SALE_ID).get(), 5);
+            assert_eq!(sc.get_remaining_nft(SALE_ID), 5u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn create_egg_nonce_then_enable_mint_on_demand() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup.blockchain_wrapper.set_esdt_local_roles(
+        setup.contract_wrapper.address_ref(),
+        &EGG_ID,
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftAddQuantity],
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.token_nonce(SALE_ID).set(0);
+
+                sc.create_egg_nonce(
+                    SALE_ID,
+                    5,
+                    ManagedBuffer::from(b"Egg"),
+                    BigUint::zero(),
+                    ManagedBuffer::new(),
+                    MultiValueEncoded::new(),
+                );
+            },
+        )
+        .assert_ok();
+
+    // the eggs held can't be sold on top of the max supply
+    setup
+        .enable_mint_on_demand(4)
+        .assert_user_error(public_sale_mint::mint::ERR_MAX_SUPPLY_BELOW_MINTED);
+    setup.enable_mint_on_demand(10).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.minted_amount(SALE_ID).get(), 5);
+            assert_eq!(sc.get_remaining_nft(SALE_ID), 10u64);
+        })
+        .assert_ok();
+
+    // changing the max supply doesn't count the eggs held twice
+    setup.enable_mint_on_demand(8).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.minted_amount(SALE_ID).get(), 5);
+            assert_eq!(sc.get_remaining_nft(SALE_ID), 8u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn fill_eggs_while_minting_on_demand() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    setup.enable_mint_on_demand(10).assert_ok();
+
+    setup.set_eggs(owner, 5);
+    setup
+        .fill_eggs_from(owner, 5)
+        .assert_user_error(public_sale_mint::mint::ERR_FILL_WHILE_MINTING);
+}
@@ -0,0 +1,220 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::elrond_codec::multi_types::OptionalValue;
+use elrond_wasm::types::{BigUint, ManagedAddress, ManagedVec, TokenIdentifier};
+use elrond_wasm_debug::{rust_biguint, DebugApi};
+use public_sale_mint::config::SaleConfig;
+use public_sale_mint::whitelist::WhitelistModule;
+use public_sale_mint::PublicSaleMint;
+
+const OTHER_SALE_ID: u64 = 2;
+const OTHER_EGG_ID: &[u8] = b"EGG2";
+const OTHER_EGG_NONCE: u64 = 1;
+const OTHER_PUBLIC_TIMESTAMP: u64 = 300;
+
+fn create_other_sale(sc: &public_sale_mint::ContractObj<DebugApi>) -> u64 {
+    let sale_id = sc.create_sale(SaleConfig {
+        max_per_wallet_first_whitelist: 2,
+        max_per_wallet_second_whitelist: 2,
+        max_per_wallet_public: 2,
+        price_per_egg: ManagedVec::from(vec![BigUint::from(20u64), BigUint::from(30u64)]),
+        reduced_price_per_egg: ManagedVec::from(vec![BigUint::from(15u64), BigUint::from(25u64)]),
+        timestamp_public_sale: OTHER_PUBLIC_TIMESTAMP,
+        second_whitelist_delta: 10,
+        first_whitelist_delta: 20,
+        sale_duration: 100,
+        reserved_supply: 0,
+        schedule: None,
+    });
+
+    sc.token_identifier(sale_id)
+        .set(TokenIdentifier::from_esdt_bytes(OTHER_EGG_ID));
+    sc.token_nonce(sale_id).set(OTHER_EGG_NONCE);
+
+    return sale_id;
+}
+
+#[test]
+fn create_sale_as_user() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            create_other_sale(&sc);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn create_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                assert_eq!(create_other_sale(&sc), OTHER_SALE_ID);
+                assert_eq!(sc.last_sale_id().get(), OTHER_SALE_ID);
+
+                assert_eq!(sc.price_per_egg(OTHER_SALE_ID).get(1), 20u64);
+                assert_eq!(sc.price_per_egg(SALE_ID).get(1), 10u64);
+                assert_eq!(
+                    sc.timestamp_first_whitelist(OTHER_SALE_ID).get(),
+                    OTHER_PUBLIC_TIMESTAMP - 20
+                );
+            },
+        )
+        .assert_ok();
+}
+
+#[test]
+fn buy_from_unknown_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(10u64), |sc| {
+            let payment = sc.call_value().payment_as_tuple();
+
+            sc.buy(
+                payment.2,
+                payment.0,
+                payment.1,
+                OTHER_SALE_ID,
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_FOUND);
+}
+
+#[test]
+fn sales_have_their_own_timeline_and_buyers() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                create_other_sale(&sc);
+            },
+        )
+        .assert_ok();
+
+    setup.blockchain_wrapper.set_nft_balance(
+        setup.contract_wrapper.address_ref(),
+        OTHER_EGG_ID,
+        OTHER_EGG_NONCE,
+        &rust_biguint!(10u64),
+        &{},
+    );
+    setup.fill_eggs(10u64);
+    setup.open_public_sale();
+
+    // the first sale is open, not the other one
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(20u64), |sc| {
+            let payment = sc.call_value().payment_as_tuple();
+
+            sc.buy(
+                payment.2,
+                payment.0,
+                payment.1,
+                OTHER_SALE_ID,
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_OPEN);
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(OTHER_PUBLIC_TIMESTAMP);
+
+    // the first sale is closed and the other one uses its own price list
+    setup
+        .buy(user, &rust_biguint!(9u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SALE_CLOSED);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(20u64), |sc| {
+            let payment = sc.call_value().payment_as_tuple();
+
+            sc.buy(
+                payment.2,
+                payment.0,
+                payment.1,
+                OTHER_SALE_ID,
+                1,
+                OptionalValue::None,
+                OptionalValue::None,
+            );
+        })
+        .assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_esdt_balance(user, OTHER_EGG_ID, OTHER_EGG_NONCE),
+        rust_biguint!(1u64)
+    );
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let user = ManagedAddress::from_address(user);
+
+            assert_eq!(sc.get_bought_amount(SALE_ID, &user), 1);
+            assert_eq!(sc.get_bought_amount(OTHER_SALE_ID, &user), 1);
+            assert_eq!(sc.get_remaining_nft(OTHER_SALE_ID), 9u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn whitelists_are_per_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = setup.user_first_whitelisted.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                create_other_sale(&sc);
+            },
+        )
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let user = ManagedAddress::from_address(&user);
+
+            assert!(sc.check_contains_first(SALE_ID, &user));
+            assert!(!sc.check_contains_first(OTHER_SALE_ID, &user));
+        })
+        .assert_ok();
+}
@@ -0,0 +1,243 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, PROPOSAL_DURATION};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::multisig::{MultisigModule, WithdrawalAsset};
+
+const FIRST_PROPOSAL: u64 = 1;
+
+#[test]
+fn set_board_with_bad_quorum() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+
+    setup
+        .set_board(3, &[member1, member2])
+        .assert_user_error(public_sale_mint::multisig::ERR_MULTISIG_BAD_QUORUM);
+    setup
+        .set_board(0, &[member1, member2])
+        .assert_user_error(public_sale_mint::multisig::ERR_MULTISIG_BAD_QUORUM);
+}
+
+#[test]
+fn claim_while_multisig_enabled() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+    let owner = &setup.owner_address.clone();
+
+    setup.set_board(2, &[member1, member2]).assert_ok();
+
+    setup
+        .claim_balance(owner)
+        .assert_user_error(public_sale_mint::multisig::ERR_MULTISIG_REQUIRED);
+}
+
+#[test]
+fn propose_while_not_board_member() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+    let outsider = &setup.users[2].clone();
+
+    setup.set_board(2, &[member1, member2]).assert_ok();
+
+    setup
+        .propose_withdrawal(outsider, WithdrawalAsset::Egld, 1, outsider)
+        .assert_user_error(public_sale_mint::multisig::ERR_NOT_BOARD_MEMBER);
+}
+
+#[test]
+fn withdraw_egld_with_quorum() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+    let buyer = &setup.users[2].clone();
+    let treasury = &setup.users[3].clone();
+
+    setup.set_board(2, &[member1, member2]).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Egld, 10, treasury)
+        .assert_ok();
+
+    setup
+        .execute_withdrawal(buyer, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_PROPOSAL_QUORUM_NOT_REACHED);
+    setup
+        .sign_withdrawal(member1, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_PROPOSAL_ALREADY_SIGNED);
+
+    setup.sign_withdrawal(member2, FIRST_PROPOSAL).assert_ok();
+    setup.execute_withdrawal(buyer, FIRST_PROPOSAL).assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(treasury),
+        rust_biguint!(150u64 + 10u64)
+    );
+
+    // a proposal is executed only once
+    setup
+        .execute_withdrawal(buyer, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_PROPOSAL_NOT_FOUND);
+}
+
+#[test]
+fn withdraw_eggs_with_quorum() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+
+    setup.set_board(1, &[member1, member2]).assert_ok();
+    setup.fill_eggs(10u64);
+
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 11, member2)
+        .assert_ok();
+    setup
+        .execute_withdrawal(member1, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_WITHDRAWAL_EXCEEDS_BALANCE);
+
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 4, member2)
+        .assert_ok();
+    setup
+        .execute_withdrawal(member1, FIRST_PROPOSAL + 1)
+        .assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(member2), rust_biguint!(4u64));
+}
+
+#[test]
+fn execute_expired_proposal() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+
+    setup.set_board(1, &[member1, member2]).assert_ok();
+    setup.fill_eggs(10u64);
+
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 1, member2)
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(PROPOSAL_DURATION);
+
+    setup
+        .execute_withdrawal(member1, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_PROPOSAL_EXPIRED);
+}
+
+#[test]
+fn get_proposals() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+
+    setup.set_board(2, &[member1, member2]).assert_ok();
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Egld, 5, member1)
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let mut proposals = sc.get_proposals().into_iter();
+
+            let (proposal_id, proposal, signatures) = proposals.next().unwrap().into_tuple();
+            assert_eq!(proposal_id, FIRST_PROPOSAL);
+            assert_eq!(proposal.amount, 5u64);
+            assert_eq!(proposal.expiration, PROPOSAL_DURATION);
+            assert_eq!(signatures, 1);
+
+            assert_eq!(proposals.next().is_none(), true);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn withdraw_eggs_owed_to_buyers() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+    let buyer = &setup.users[2].clone();
+    let claim_timestamp = setup.public_timestamp + setup.sale_duration;
+
+    setup.set_board(1, &[member1, member2]).assert_ok();
+    setup.set_reserved_supply(2);
+    setup.set_claim_timestamp(claim_timestamp).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(5u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 3, member2)
+        .assert_ok();
+    setup
+        .execute_withdrawal(member1, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_WITHDRAWAL_EXCEEDS_BALANCE);
+
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 2, member2)
+        .assert_ok();
+    setup
+        .execute_withdrawal(member1, FIRST_PROPOSAL + 1)
+        .assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(member2), rust_biguint!(2u64));
+}
+
+#[test]
+fn execute_after_board_disabled() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+    let outsider = &setup.users[2].clone();
+
+    setup.set_board(1, &[member1, member2]).assert_ok();
+    setup.fill_eggs(10u64);
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 1, outsider)
+        .assert_ok();
+
+    setup.set_board(0, &[]).assert_ok();
+
+    setup
+        .execute_withdrawal(outsider, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_MULTISIG_DISABLED);
+    assert_eq!(setup.get_eggs_balance(outsider), rust_biguint!(0u64));
+}
+
+#[test]
+fn board_change_drops_open_proposals() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let member1 = &setup.users[0].clone();
+    let member2 = &setup.users[1].clone();
+    let member3 = &setup.users[2].clone();
+
+    setup.set_board(1, &[member1, member2]).assert_ok();
+    setup.fill_eggs(10u64);
+    setup
+        .propose_withdrawal(member1, WithdrawalAsset::Eggs, 1, member1)
+        .assert_ok();
+
+    setup.set_board(1, &[member1, member3]).assert_ok();
+
+    setup
+        .execute_withdrawal(member1, FIRST_PROPOSAL)
+        .assert_user_error(public_sale_mint::multisig::ERR_PROPOSAL_NOT_FOUND);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.proposals().len(), 0);
+            assert_eq!(sc.proposal_signers(FIRST_PROPOSAL).len(), 0);
+        })
+        .assert_ok();
+}
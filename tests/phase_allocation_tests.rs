@@ -0,0 +1,95 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::{allocation::AllocationModule, phase::SalePhase};
+
+#[test]
+fn no_allocation_is_uncapped() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    assert_eq!(
+        setup.get_remaining_allocation(SalePhase::FirstWhitelist),
+        None
+    );
+    assert_eq!(setup.get_remaining_allocation(SalePhase::Public), None);
+}
+
+#[test]
+fn set_allocation_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_phase_allocation(SALE_ID, SalePhase::Public, 5);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn set_allocation_on_closed_phase() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_phase_allocation(SalePhase::Closed, 5)
+        .assert_user_error(public_sale_mint::allocation::ERR_ALLOCATION_NOT_SELLING_PHASE);
+}
+
+#[test]
+fn buy_more_than_allocation() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.user_first_whitelisted.clone();
+
+    setup
+        .set_phase_allocation(SalePhase::FirstWhitelist, 1)
+        .assert_ok();
+    setup.fill_eggs(10u64);
+    setup.open_first_whitelist();
+
+    setup
+        .buy(user, &rust_biguint!(9u64 + 9u64), 2)
+        .assert_user_error(public_sale_mint::allocation::ERR_ALLOCATION_EXCEEDED);
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+    setup
+        .buy(user, &rust_biguint!(9u64), 1)
+        .assert_user_error(public_sale_mint::allocation::ERR_ALLOCATION_EXCEEDED);
+
+    assert_eq!(
+        setup.get_remaining_allocation(SalePhase::FirstWhitelist),
+        Some(0)
+    );
+    assert_eq!(setup.get_buyed_amount(user), 1);
+}
+
+#[test]
+fn unsold_allocation_rolls_over() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.user_first_whitelisted.clone();
+
+    setup
+        .set_phase_allocation(SalePhase::FirstWhitelist, 3)
+        .assert_ok();
+    setup
+        .set_phase_allocation(SalePhase::SecondWhitelist, 2)
+        .assert_ok();
+    setup.fill_eggs(10u64);
+
+    setup.open_first_whitelist();
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup.open_second_whitelist();
+    assert_eq!(
+        setup.get_remaining_allocation(SalePhase::SecondWhitelist),
+        Some(4)
+    );
+
+    setup.buy(user, &rust_biguint!(6u64 * 4), 4).assert_ok();
+
+    assert_eq!(
+        setup.get_remaining_allocation(SalePhase::SecondWhitelist),
+        Some(0)
+    );
+    assert_eq!(setup.get_remaining_allocation(SalePhase::Public), None);
+}
@@ -0,0 +1,129 @@
+mod contract_setup;
+
+use contract_setup::setup_contract;
+use elrond_wasm::contract_base::ContractBase;
+use elrond_wasm::types::ManagedBuffer;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::promo::PromoModule;
+
+const PROMO_CODE: &[u8] = b"PENGUINS10";
+const EXPIRATION: u64 = 200;
+
+#[test]
+fn register_promo_code_bad_hash() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            &setup.owner_address,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.register_promo_code(ManagedBuffer::from(PROMO_CODE), 1_000, 1, EXPIRATION);
+            },
+        )
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_BAD_HASH);
+}
+
+#[test]
+fn register_promo_code_bad_parameters() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .register_promo_code(PROMO_CODE, 0, 1, EXPIRATION)
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_BAD_DISCOUNT);
+    setup
+        .register_promo_code(PROMO_CODE, 10_001, 1, EXPIRATION)
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_BAD_DISCOUNT);
+    setup
+        .register_promo_code(PROMO_CODE, 1_000, 0, EXPIRATION)
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_NO_USAGE);
+}
+
+#[test]
+fn buy_with_promo_code() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .register_promo_code(PROMO_CODE, 1_000, 5, EXPIRATION)
+        .assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy_with_promo_code(user, &rust_biguint!(10u64), 1, PROMO_CODE)
+        .assert_user_error(public_sale_mint::ERR_BAD_AMOUNT_SENT);
+    setup
+        .buy_with_promo_code(user, &rust_biguint!(9u64), 1, PROMO_CODE)
+        .assert_ok();
+
+    assert_eq!(setup.get_buyed_amount(user), 1);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let code_hash = sc
+                .crypto()
+                .sha256(&ManagedBuffer::from(PROMO_CODE))
+                .as_managed_buffer()
+                .clone();
+
+            assert_eq!(sc.get_promo_code_usage(&code_hash), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn buy_with_unknown_promo_code() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .register_promo_code(PROMO_CODE, 1_000, 5, EXPIRATION)
+        .assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy_with_promo_code(user, &rust_biguint!(9u64), 1, b"PENGUINS20")
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_NOT_FOUND);
+}
+
+#[test]
+fn buy_with_expired_promo_code() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .register_promo_code(PROMO_CODE, 1_000, 5, EXPIRATION)
+        .assert_ok();
+    setup.fill_eggs(10u64);
+
+    setup.blockchain_wrapper.set_block_timestamp(EXPIRATION);
+
+    setup
+        .buy_with_promo_code(user, &rust_biguint!(9u64), 1, PROMO_CODE)
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_EXPIRED);
+}
+
+#[test]
+fn buy_with_used_up_promo_code() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let first_user = &setup.users[0].clone();
+    let second_user = &setup.users[1].clone();
+
+    setup
+        .register_promo_code(PROMO_CODE, 1_000, 1, EXPIRATION)
+        .assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy_with_promo_code(first_user, &rust_biguint!(9u64), 1, PROMO_CODE)
+        .assert_ok();
+    setup
+        .buy_with_promo_code(second_user, &rust_biguint!(9u64), 1, PROMO_CODE)
+        .assert_user_error(public_sale_mint::promo::ERR_PROMO_CODE_USED_UP);
+}
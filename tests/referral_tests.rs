@@ -0,0 +1,139 @@
+mod contract_setup;
+
+use contract_setup::setup_contract;
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::referral::ReferralModule;
+
+#[test]
+fn set_referral_program_too_high() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_referral_program(6_000, 5_000)
+        .assert_user_error(public_sale_mint::referral::ERR_REFERRAL_TOO_HIGH);
+}
+
+#[test]
+fn set_referral_program_as_user() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_referral_program(1_000, 0);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn buy_with_self_as_referrer() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy_with_referrer(user, &rust_biguint!(10u64), 1, user)
+        .assert_user_error(public_sale_mint::referral::ERR_SELF_REFERRAL);
+}
+
+#[test]
+fn buy_with_referrer() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let referrer = &setup.users[1].clone();
+
+    setup.set_referral_program(2_000, 1_000).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    // the referee gets 10% off
+    setup
+        .buy_with_referrer(buyer, &rust_biguint!(9u64 + 9u64), 2, referrer)
+        .assert_user_error(public_sale_mint::ERR_BAD_AMOUNT_SENT);
+    setup
+        .buy_with_referrer(buyer, &rust_biguint!(16u64), 2, referrer)
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let referrer = ManagedAddress::from_address(referrer);
+
+            // 20% of 16, rounded down
+            assert_eq!(sc.get_referral_rewards(&referrer), 3u64);
+            assert_eq!(sc.get_referral_earnings(&referrer), 3u64);
+            assert_eq!(sc.get_referral_count(&referrer), 1);
+            assert_eq!(sc.total_referral_rewards().get(), 3u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn claim_referral_rewards() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let referrer = &setup.users[1].clone();
+
+    setup.set_referral_program(2_000, 0).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy_with_referrer(buyer, &rust_biguint!(10u64), 1, referrer)
+        .assert_ok();
+
+    let balance_before = setup.blockchain_wrapper.get_egld_balance(referrer);
+
+    setup.claim_referral_rewards(referrer).assert_ok();
+
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(referrer),
+        balance_before + 2u64
+    );
+
+    setup
+        .claim_referral_rewards(referrer)
+        .assert_user_error(public_sale_mint::referral::ERR_NO_REFERRAL_REWARDS);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let referrer = ManagedAddress::from_address(referrer);
+
+            assert_eq!(sc.get_referral_rewards(&referrer), 0u64);
+            assert_eq!(sc.get_referral_earnings(&referrer), 2u64);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn claim_balance_keeps_referral_rewards() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let referrer = &setup.users[1].clone();
+
+    setup.set_referral_program(2_000, 0).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .buy_with_referrer(buyer, &rust_biguint!(10u64), 1, referrer)
+        .assert_ok();
+
+    setup
+        .claim_balance(&setup.owner_address.clone())
+        .assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(&setup.owner_address),
+        rust_biguint!(8u64)
+    );
+
+    setup.claim_referral_rewards(referrer).assert_ok();
+}
@@ -0,0 +1,126 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::reserve::ReserveModule;
+
+const RESERVE: u64 = 3;
+
+#[test]
+fn mint_reserved_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_reserved_supply(RESERVE);
+    setup.fill_eggs(10u64);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.mint_reserved(SALE_ID, ManagedAddress::from_address(user), 1);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn mint_reserved() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_reserved_supply(RESERVE);
+    setup.fill_eggs(10u64);
+
+    setup.mint_reserved(user, 2).assert_ok();
+
+    assert_eq!(setup.get_eggs_balance(user), rust_biguint!(2u64));
+    assert_eq!(setup.get_buyed_amount(user), 0);
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(user),
+        rust_biguint!(150u64)
+    );
+}
+
+#[test]
+fn mint_more_than_reserve() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_reserved_supply(RESERVE);
+    setup.fill_eggs(10u64);
+
+    setup
+        .mint_reserved(user, RESERVE + 1)
+        .assert_user_error(public_sale_mint::reserve::ERR_RESERVE_EXCEEDED);
+
+    setup.mint_reserved(user, RESERVE).assert_ok();
+    setup
+        .mint_reserved(user, 1)
+        .assert_user_error(public_sale_mint::reserve::ERR_RESERVE_EXCEEDED);
+}
+
+#[test]
+fn buy_cannot_take_the_reserve() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let team = &setup.users[1].clone();
+
+    setup.set_reserved_supply(RESERVE);
+    setup.fill_eggs(RESERVE + 1);
+    setup.open_public_sale();
+
+    setup.buy(user, &rust_biguint!(10u64), 1).assert_ok();
+    setup
+        .buy(user, &rust_biguint!(9u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SOLD_OUT);
+
+    setup.mint_reserved(team, RESERVE).assert_ok();
+    assert_eq!(setup.get_eggs_balance(team), rust_biguint!(RESERVE));
+}
+
+#[test]
+fn reserved_mints_are_tracked_separately() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup.set_reserved_supply(RESERVE);
+    setup.fill_eggs(10u64);
+
+    setup.mint_reserved(user, 1).assert_ok();
+    setup.mint_reserved(user, 1).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_remaining_reserve(SALE_ID), RESERVE - 2);
+
+            let mut mints = sc.get_all_reserved_mints(SALE_ID).into_iter();
+            assert_eq!(
+                mints.next().unwrap().into_tuple(),
+                (ManagedAddress::from_address(user), 2)
+            );
+            assert_eq!(mints.next().is_none(), true);
+        })
+        .assert_ok();
+
+    assert_eq!(setup.get_all_buyers().into_iter().next().is_none(), true);
+}
+
+#[test]
+fn buy_several_eggs_cannot_take_the_reserve() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+    let team = &setup.users[1].clone();
+
+    setup.set_reserved_supply(RESERVE);
+    setup.fill_eggs(RESERVE + 2);
+    setup.open_public_sale();
+
+    setup
+        .buy(user, &rust_biguint!(8u64 * 3), 3)
+        .assert_user_error(public_sale_mint::ERR_SOLD_OUT);
+    setup.buy(user, &rust_biguint!(9u64 * 2), 2).assert_ok();
+
+    setup.mint_reserved(team, RESERVE).assert_ok();
+    assert_eq!(setup.get_eggs_balance(team), rust_biguint!(RESERVE));
+}
@@ -0,0 +1,236 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::types::ManagedAddress;
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::{
+    admin::AdminModule,
+    anti_bot::AntiBotModule,
+    pause::PauseModule,
+    roles::{Role, RolesModule},
+    whitelist::WhitelistModule,
+};
+
+#[test]
+fn grant_role_while_not_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.grant_role(Role::Pauser, ManagedAddress::from_address(user));
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
+
+#[test]
+fn whitelist_manager_adds_to_whitelist() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let manager = &setup.users[0].clone();
+    let user = &setup.users[1].clone();
+
+    setup
+        .grant_role(Role::WhitelistManager, manager)
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            manager,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.add_to_first_whitelist(SALE_ID, &ManagedAddress::from_address(user));
+            },
+        )
+        .assert_ok();
+
+    assert_eq!(setup.is_first_whitelisted(user.clone()), true);
+}
+
+#[test]
+fn revoked_whitelist_manager() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let manager = &setup.users[0].clone();
+    let user = &setup.users[1].clone();
+
+    setup
+        .grant_role(Role::WhitelistManager, manager)
+        .assert_ok();
+    setup
+        .revoke_role(Role::WhitelistManager, manager)
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            manager,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.add_to_second_whitelist(SALE_ID, &ManagedAddress::from_address(user));
+            },
+        )
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
+}
+
+#[test]
+fn treasurer_claims_balance_for_owner() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let treasurer = &setup.users[0].clone();
+    let buyer = &setup.users[1].clone();
+
+    setup.grant_role(Role::Treasurer, treasurer).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+
+    setup.claim_balance(treasurer).assert_ok();
+
+    assert_eq!(
+        setup
+            .blockchain_wrapper
+            .get_egld_balance(&setup.owner_address),
+        rust_biguint!(10u64)
+    );
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(treasurer),
+        rust_biguint!(150u64)
+    );
+}
+
+#[test]
+fn treasurer_cannot_manage_whitelist() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let treasurer = &setup.users[0].clone();
+
+    setup.grant_role(Role::Treasurer, treasurer).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            treasurer,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.add_to_first_whitelist(SALE_ID, &ManagedAddress::from_address(treasurer));
+            },
+        )
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
+}
+
+#[test]
+fn pauser_pauses_the_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let pauser = &setup.users[0].clone();
+    let buyer = &setup.users[1].clone();
+
+    setup.grant_role(Role::Pauser, pauser).assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            pauser,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.pause();
+            },
+        )
+        .assert_ok();
+
+    setup
+        .buy(buyer, &rust_biguint!(10u64), 1)
+        .assert_user_error(public_sale_mint::pause::ERR_SALE_PAUSED);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(
+            pauser,
+            &setup.contract_wrapper,
+            &rust_biguint!(0u64),
+            |sc| {
+                sc.unpause();
+            },
+        )
+        .assert_ok();
+
+    setup.buy(buyer, &rust_biguint!(10u64), 1).assert_ok();
+}
+
+#[test]
+fn admin_has_every_role() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let admin = &setup.owner_address.clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(admin, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.add_to_first_whitelist(SALE_ID, &ManagedAddress::from_address(admin));
+            sc.pause();
+
+            assert_eq!(
+                sc.has_role(Role::Pauser, &ManagedAddress::from_address(admin)),
+                false
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn admin_role_has_every_role() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let admin = &setup.users[0].clone();
+
+    setup.grant_role(Role::Admin, admin).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(admin, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.add_to_first_whitelist(SALE_ID, &ManagedAddress::from_address(admin));
+            sc.pause();
+            sc.set_anti_bot_limits(1, 0);
+            sc.grant_role(Role::Pauser, ManagedAddress::from_address(admin));
+
+            assert_eq!(
+                sc.has_role(Role::Admin, &ManagedAddress::from_address(admin)),
+                true
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn admin_role_cannot_hand_over_the_admin_rights() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let admin = &setup.users[0].clone();
+    let user = &setup.users[1].clone();
+
+    setup.grant_role(Role::Admin, admin).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(admin, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.grant_role(Role::Admin, ManagedAddress::from_address(user));
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(admin, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.propose_admin(ManagedAddress::from_address(admin));
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+
+    setup.revoke_role(Role::Admin, admin).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(admin, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_anti_bot_limits(1, 0);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
@@ -0,0 +1,188 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm::types::{BigUint, ManagedVec};
+use elrond_wasm_debug::{rust_biguint, tx_mock::TxResult, DebugApi};
+use public_sale_mint::{
+    config::{SaleConfig, SaleSchedule},
+    delayed_claim::DelayedClaimModule,
+    holders::HoldersModule,
+    PublicSaleMint,
+};
+
+const OTHER_SALE_ID: u64 = 2;
+
+fn create_sale_with_schedule(
+    sc: &public_sale_mint::ContractObj<DebugApi>,
+    first_whitelist: u64,
+    second_whitelist: u64,
+    public_sale: u64,
+    sale_closed: u64,
+) {
+    sc.create_sale(SaleConfig {
+        max_per_wallet_first_whitelist: 1,
+        max_per_wallet_second_whitelist: 1,
+        max_per_wallet_public: 1,
+        price_per_egg: ManagedVec::from(vec![BigUint::from(20u64)]),
+        reduced_price_per_egg: ManagedVec::from(vec![BigUint::from(15u64)]),
+        // ignored in favor of the schedule
+        timestamp_public_sale: 0,
+        second_whitelist_delta: 0,
+        first_whitelist_delta: 0,
+        sale_duration: 0,
+        reserved_supply: 0,
+        schedule: Some(SaleSchedule {
+            timestamp_first_whitelist: first_whitelist,
+            timestamp_second_whitelist: second_whitelist,
+            timestamp_public_sale: public_sale,
+            timestamp_sale_closed: sale_closed,
+        }),
+    });
+}
+
+fn assert_schedule<ContractObjBuilder>(
+    setup: &mut contract_setup::ContractSetup<ContractObjBuilder>,
+    expected: [u64; 4],
+) where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_mint::ContractObj<DebugApi>,
+{
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let schedule = sc.get_sale_schedule(SALE_ID);
+
+            assert_eq!(
+                [
+                    schedule.timestamp_first_whitelist,
+                    schedule.timestamp_second_whitelist,
+                    schedule.timestamp_public_sale,
+                    schedule.timestamp_sale_closed,
+                ],
+                expected
+            );
+        })
+        .assert_ok();
+}
+
+fn create_other_sale<ContractObjBuilder>(
+    setup: &mut contract_setup::ContractSetup<ContractObjBuilder>,
+    schedule: [u64; 4],
+) -> TxResult
+where
+    ContractObjBuilder: 'static + Copy + Fn() -> public_sale_mint::ContractObj<DebugApi>,
+{
+    return setup.blockchain_wrapper.execute_tx(
+        &setup.owner_address,
+        &setup.contract_wrapper,
+        &rust_biguint!(0u64),
+        |sc| {
+            create_sale_with_schedule(&sc, schedule[0], schedule[1], schedule[2], schedule[3]);
+        },
+    );
+}
+
+#[test]
+fn create_sale_with_absolute_schedule() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    create_other_sale(&mut setup, [500, 550, 600, 700]).assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            let schedule = sc.get_sale_schedule(OTHER_SALE_ID);
+            assert_eq!(schedule.timestamp_first_whitelist, 500);
+            assert_eq!(schedule.timestamp_second_whitelist, 550);
+            assert_eq!(schedule.timestamp_public_sale, 600);
+            assert_eq!(schedule.timestamp_sale_closed, 700);
+
+            let config = sc.get_sale_config(OTHER_SALE_ID);
+            assert_eq!(config.first_whitelist_delta, 100);
+            assert_eq!(config.second_whitelist_delta, 50);
+            assert_eq!(config.sale_duration, 100);
+            assert!(config.schedule == Some(schedule));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn create_sale_with_unordered_schedule() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    create_other_sale(&mut setup, [550, 500, 600, 700])
+        .assert_user_error(public_sale_mint::ERR_SCHEDULE_SECOND_WL_BEFORE_FIRST);
+    create_other_sale(&mut setup, [500, 650, 600, 700])
+        .assert_user_error(public_sale_mint::ERR_SCHEDULE_PUBLIC_BEFORE_SECOND_WL);
+    create_other_sale(&mut setup, [500, 550, 600, 600])
+        .assert_user_error(public_sale_mint::ERR_INIT_CLOSE_BEFORE_OPEN);
+}
+
+#[test]
+fn postpone_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    setup.set_holder_sale();
+    setup.set_claim_timestamp(300).assert_ok();
+    setup.postpone_sale(owner, 1_000).assert_ok();
+
+    assert_schedule(&mut setup, [1_080, 1_100, 1_120, 1_260]);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.timestamp_holder_sale(SALE_ID).get(), 1_090);
+            assert_eq!(sc.claim_timestamp(SALE_ID).get(), 1_300);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn advance_sale() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    setup
+        .advance_sale(owner, 81)
+        .assert_user_error(public_sale_mint::ERR_SCHEDULE_UNDERFLOW);
+    setup.advance_sale(owner, 80).assert_ok();
+
+    assert_schedule(&mut setup, [0, 20, 40, 180]);
+}
+
+#[test]
+fn postpone_sale_overflow() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    setup
+        .postpone_sale(owner, u64::MAX - 200)
+        .assert_user_error(public_sale_mint::ERR_SCHEDULE_OVERFLOW);
+
+    assert_schedule(&mut setup, [80, 100, 120, 260]);
+}
+
+#[test]
+fn shift_schedule_after_start() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = &setup.owner_address.clone();
+
+    setup.open_first_whitelist();
+
+    setup
+        .postpone_sale(owner, 100)
+        .assert_user_error(public_sale_mint::ERR_SCHEDULE_SALE_STARTED);
+    setup
+        .advance_sale(owner, 10)
+        .assert_user_error(public_sale_mint::ERR_SCHEDULE_SALE_STARTED);
+}
+
+#[test]
+fn shift_schedule_while_not_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = &setup.users[0].clone();
+
+    setup
+        .postpone_sale(user, 100)
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
@@ -0,0 +1,163 @@
+mod contract_setup;
+
+use contract_setup::setup_contract;
+use elrond_wasm::types::{BigUint, ManagedVec};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::config::SaleConfig;
+use public_sale_mint::PublicSaleMint;
+
+const VESTING_DURATION: u64 = 100;
+
+#[test]
+fn set_vesting_schedule_twice() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_vesting_schedule(2_500, VESTING_DURATION)
+        .assert_ok();
+    setup
+        .set_vesting_schedule(10_000, 0)
+        .assert_user_error(public_sale_mint::revenue::ERR_VESTING_ALREADY_SET);
+}
+
+#[test]
+fn set_vesting_schedule_with_bad_initial_unlock() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_vesting_schedule(10_001, VESTING_DURATION)
+        .assert_user_error(public_sale_mint::revenue::ERR_VESTING_INITIAL_UNLOCK_TOO_HIGH);
+}
+
+#[test]
+fn vesting_and_forwarding_are_exclusive() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup.set_forward_revenue(true).assert_ok();
+    setup
+        .set_vesting_schedule(2_500, VESTING_DURATION)
+        .assert_user_error(public_sale_mint::revenue::ERR_VESTING_WHILE_FORWARDING);
+
+    setup.set_forward_revenue(false).assert_ok();
+    setup
+        .set_vesting_schedule(2_500, VESTING_DURATION)
+        .assert_ok();
+    setup
+        .set_forward_revenue(true)
+        .assert_user_error(public_sale_mint::revenue::ERR_VESTING_WHILE_FORWARDING);
+}
+
+#[test]
+fn claim_vested_balance() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+
+    setup
+        .set_vesting_schedule(2_500, VESTING_DURATION)
+        .assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    // nothing is vested before the sale closes
+    setup
+        .claim_balance(owner)
+        .assert_user_error("There is nothing to claim. The balance is empty.");
+
+    // 25% of 18 is unlocked at close
+    setup.close_sale();
+    setup.claim_balance(owner).assert_ok();
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(owner),
+        rust_biguint!(4u64)
+    );
+
+    // then the remaining 14 are released linearly
+    let sale_closed = setup.public_timestamp + setup.sale_duration;
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(sale_closed + VESTING_DURATION / 2);
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_vested_amount(), 4u64 + 7u64);
+            assert_eq!(sc.get_releasable_amount(), 7u64);
+        })
+        .assert_ok();
+
+    setup.claim_balance(owner).assert_ok();
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(owner),
+        rust_biguint!(11u64)
+    );
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(sale_closed + VESTING_DURATION);
+    setup.claim_balance(owner).assert_ok();
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(owner),
+        rust_biguint!(18u64)
+    );
+}
+
+#[test]
+fn create_sale_after_vesting_started() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let buyer = &setup.users[0].clone();
+    let owner = &setup.owner_address.clone();
+
+    setup
+        .set_vesting_schedule(2_500, VESTING_DURATION)
+        .assert_ok();
+    setup.open_public_sale();
+    setup.fill_eggs(10u64);
+    setup.buy(buyer, &rust_biguint!(9u64 + 9u64), 2).assert_ok();
+
+    setup.close_sale();
+    setup.claim_balance(owner).assert_ok();
+
+    // a sale closing later doesn't move the start of the vesting once released
+    let sale_closed = setup.public_timestamp + setup.sale_duration;
+    setup
+        .blockchain_wrapper
+        .execute_tx(owner, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.create_sale(SaleConfig {
+                max_per_wallet_first_whitelist: 2,
+                max_per_wallet_second_whitelist: 2,
+                max_per_wallet_public: 2,
+                price_per_egg: ManagedVec::from(vec![BigUint::from(20u64), BigUint::from(30u64)]),
+                reduced_price_per_egg: ManagedVec::from(vec![
+                    BigUint::from(15u64),
+                    BigUint::from(25u64),
+                ]),
+                timestamp_public_sale: sale_closed + 100,
+                second_whitelist_delta: 10,
+                first_whitelist_delta: 20,
+                sale_duration: 100,
+                reserved_supply: 0,
+                schedule: None,
+            });
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .execute_query(&setup.contract_wrapper, |sc| {
+            assert_eq!(sc.get_vesting_start(), sale_closed);
+            assert_eq!(sc.get_vested_amount(), 4u64);
+            assert_eq!(sc.get_releasable_amount(), 0u64);
+        })
+        .assert_ok();
+
+    setup
+        .blockchain_wrapper
+        .set_block_timestamp(sale_closed + VESTING_DURATION);
+    setup.claim_balance(owner).assert_ok();
+    assert_eq!(
+        setup.blockchain_wrapper.get_egld_balance(owner),
+        rust_biguint!(18u64)
+    );
+}
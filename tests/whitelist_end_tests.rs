@@ -0,0 +1,102 @@
+mod contract_setup;
+
+use contract_setup::{setup_contract, SALE_ID};
+use elrond_wasm_debug::rust_biguint;
+use public_sale_mint::{phase::SalePhase, whitelist::WhitelistModule};
+
+#[test]
+fn first_whitelist_closes_at_its_end() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let first_whitelisted = setup.user_first_whitelisted.clone();
+    let second_whitelisted = setup.user_second_whitelisted.clone();
+
+    setup.set_whitelist_ends(Some(90), None).assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(89);
+    assert_eq!(setup.has_access(&first_whitelisted), true);
+    assert!(setup.get_current_phase() == SalePhase::FirstWhitelist);
+
+    setup.blockchain_wrapper.set_block_timestamp(90);
+    assert_eq!(setup.has_access(&first_whitelisted), false);
+    assert!(setup.get_current_phase() == SalePhase::BetweenPhases);
+
+    setup.open_second_whitelist();
+    assert_eq!(setup.has_access(&first_whitelisted), false);
+    assert_eq!(setup.has_access(&second_whitelisted), true);
+    assert!(setup.get_current_phase() == SalePhase::SecondWhitelist);
+
+    setup.open_public_sale();
+    assert_eq!(setup.has_access(&first_whitelisted), true);
+    assert!(setup.get_current_phase() == SalePhase::Public);
+}
+
+#[test]
+fn whitelists_pause_until_public() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let first_whitelisted = setup.user_first_whitelisted.clone();
+    let second_whitelisted = setup.user_second_whitelisted.clone();
+
+    setup.set_whitelist_ends(Some(90), Some(110)).assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(110);
+    assert_eq!(setup.has_access(&first_whitelisted), false);
+    assert_eq!(setup.has_access(&second_whitelisted), false);
+    assert!(setup.get_current_phase() == SalePhase::BetweenPhases);
+
+    setup
+        .buy(&second_whitelisted, &rust_biguint!(5u64), 1)
+        .assert_user_error(public_sale_mint::ERR_SALE_NOT_OPEN);
+}
+
+#[test]
+fn remove_whitelist_end() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let first_whitelisted = setup.user_first_whitelisted.clone();
+
+    setup.set_whitelist_ends(Some(90), None).assert_ok();
+    setup.set_whitelist_ends(None, None).assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(95);
+    assert_eq!(setup.has_access(&first_whitelisted), true);
+}
+
+#[test]
+fn set_invalid_whitelist_ends() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+
+    setup
+        .set_whitelist_ends(Some(80), None)
+        .assert_user_error(public_sale_mint::whitelist::ERR_WHITELIST_END_BEFORE_START);
+    setup
+        .set_whitelist_ends(None, Some(121))
+        .assert_user_error(public_sale_mint::whitelist::ERR_WHITELIST_END_AFTER_PUBLIC);
+}
+
+#[test]
+fn postpone_sale_moves_whitelist_ends() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let owner = setup.owner_address.clone();
+    let first_whitelisted = setup.user_first_whitelisted.clone();
+
+    setup.set_whitelist_ends(Some(90), None).assert_ok();
+    setup.postpone_sale(&owner, 100).assert_ok();
+
+    setup.blockchain_wrapper.set_block_timestamp(189);
+    assert_eq!(setup.has_access(&first_whitelisted), true);
+
+    setup.blockchain_wrapper.set_block_timestamp(190);
+    assert_eq!(setup.has_access(&first_whitelisted), false);
+}
+
+#[test]
+fn set_whitelist_ends_while_not_admin() {
+    let mut setup = setup_contract(public_sale_mint::contract_obj);
+    let user = setup.users[0].clone();
+
+    setup
+        .blockchain_wrapper
+        .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
+            sc.set_whitelist_ends(SALE_ID, Some(90), None);
+        })
+        .assert_user_error(public_sale_mint::ERR_NOT_OWNER);
+}
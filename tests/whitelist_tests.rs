@@ -1,6 +1,6 @@
 mod contract_setup;
 
-use contract_setup::setup_contract;
+use contract_setup::{setup_contract, SALE_ID};
 use elrond_wasm::types::ManagedAddress;
 use elrond_wasm_debug::rust_biguint;
 use public_sale_mint::whitelist::WhitelistModule;
@@ -15,7 +15,7 @@ fn add_to_first_whitelisted() {
     setup
         .blockchain_wrapper
         .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
-            let contains = sc.check_contains_first(&ManagedAddress::from_address(&user));
+            let contains = sc.check_contains_first(SALE_ID, &ManagedAddress::from_address(&user));
             assert_eq!(contains, true);
         })
         .assert_ok();
@@ -31,7 +31,7 @@ fn add_to_second_whitelisted() {
     setup
         .blockchain_wrapper
         .execute_tx(&user, &setup.contract_wrapper, &rust_biguint!(0u64), |sc| {
-            let contains = sc.check_contains_second(&ManagedAddress::from_address(&user));
+            let contains = sc.check_contains_second(SALE_ID, &ManagedAddress::from_address(&user));
             assert_eq!(contains, true);
         })
         .assert_ok();
@@ -50,10 +50,10 @@ fn normal_user_adding_to_first_wl() {
             &setup.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.add_to_first_whitelist(&ManagedAddress::from_address(&address));
+                sc.add_to_first_whitelist(SALE_ID, &ManagedAddress::from_address(&address));
             },
         )
-        .assert_user_error(public_sale_mint::whitelist::ERR_NOT_OWNER);
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
 }
 
 #[test]
@@ -69,10 +69,10 @@ fn normal_user_adding_to_second_wl() {
             &setup.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.add_to_second_whitelist(&ManagedAddress::from_address(&address));
+                sc.add_to_second_whitelist(SALE_ID, &ManagedAddress::from_address(&address));
             },
         )
-        .assert_user_error(public_sale_mint::whitelist::ERR_NOT_OWNER);
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
 }
 
 #[test]
@@ -88,7 +88,8 @@ fn check_contains_first_on_not_whitelisted() {
             &setup.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                let contains = sc.check_contains_first(&ManagedAddress::from_address(&address));
+                let contains =
+                    sc.check_contains_first(SALE_ID, &ManagedAddress::from_address(&address));
                 assert_eq!(contains, false);
             },
         )
@@ -108,7 +109,8 @@ fn check_contains_second_on_not_whitelisted() {
             &setup.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                let contains = sc.check_contains_second(&ManagedAddress::from_address(&address));
+                let contains =
+                    sc.check_contains_second(SALE_ID, &ManagedAddress::from_address(&address));
                 assert_eq!(contains, false);
             },
         )
@@ -150,10 +152,10 @@ fn remove_first_whitelist_while_not_owner() {
             &setup.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.remove_from_first_whitelist(&ManagedAddress::from_address(&address));
+                sc.remove_from_first_whitelist(SALE_ID, &ManagedAddress::from_address(&address));
             },
         )
-        .assert_user_error(public_sale_mint::whitelist::ERR_NOT_OWNER);
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
 }
 
 #[test]
@@ -171,10 +173,10 @@ fn remove_second_whitelist_while_not_owner() {
             &setup.contract_wrapper,
             &rust_biguint!(0u64),
             |sc| {
-                sc.remove_from_second_whitelist(&ManagedAddress::from_address(&address));
+                sc.remove_from_second_whitelist(SALE_ID, &ManagedAddress::from_address(&address));
             },
         )
-        .assert_user_error(public_sale_mint::whitelist::ERR_NOT_OWNER);
+        .assert_user_error(public_sale_mint::roles::ERR_MISSING_ROLE);
 }
 
 #[test]
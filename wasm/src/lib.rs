@@ -7,30 +7,136 @@
 elrond_wasm_node::wasm_endpoints! {
     public_sale_mint
     (
+        accept_admin
         add_to_first_whitelist
         add_to_second_whitelist
+        advance_sale
+        airdrop
         buy
+        cancel_admin_proposal
         check_contains_first
         check_contains_second
+        claim
         claim_balance
         claim_eggs
+        claim_referral_rewards
+        claim_timestamp
+        create_egg_nonce
+        create_sale
+        enable_mint_on_demand
+        execute_withdrawal
         fill_egg
+        forward_revenue
+        getAdmin
+        getAirdroppedAmount
+        getAllAirdrops
         getAllBuyers
+        getAllPendingEggs
+        getAllReferrers
+        getAllReservedMints
+        getAllocationOverride
+        getAllocationOverrides
+        getAvailableEggs
+        getBoardMembers
         getBoughtAmount
+        getBuyerRecord
+        getBuyerTier
+        getCurrentPhase
+        getHeldAmount
+        getHolderDiscount
+        getHolderDiscounts
+        getHolderPricePreview
+        getLastSaleId
+        getMaxPerWallet
+        getMaxSupply
+        getMintedAmount
+        getPayees
+        getPendingAdmin
+        getPendingEggs
+        getPriceQuote
+        getPromoCode
+        getPromoCodeUsage
+        getPromoCodes
+        getProposalSigners
+        getProposals
+        getReferralCount
+        getReferralEarnings
+        getReferralRewards
+        getReleasableAmount
+        getReleased
+        getReleasedAmount
+        getRemainingAllocation
+        getRemainingAllocations
         getRemainingNft
+        getRemainingReserve
+        getReservedMintedAmount
+        getRoleMembers
+        getSaleConfig
+        getSaleSchedule
+        getSales
+        getStorageVersion
+        getTotalPendingEggs
+        getTotalReferralRewards
+        getVestedAmount
+        getVestingSchedule
+        getVestingStart
+        grant_role
+        hasRole
         has_access
+        holder_token
+        isVerifiedHolder
+        issue_egg_collection
+        max_per_block
         max_per_wallet
+        migrate
+        migrate_whitelisted
+        mint_reserved
+        pause
+        paused
+        postpone_sale
         price_per_egg
+        proposal_duration
+        propose_admin
+        propose_withdrawal
+        prove_holding
+        purchase_cooldown
+        quorum
         reduced_price_per_egg
+        referee_discount
+        referral_reward
+        register_promo_code
+        remove_allocation_override
         remove_from_first_whitelist
         remove_from_second_whitelist
+        remove_phase_allocation
+        remove_promo_code
         require_contains_first
         require_contains_second
+        reserved_supply
+        reveal
+        revoke_role
+        set_allocation_override
+        set_anti_bot_limits
+        set_board
+        set_claim_timestamp
+        set_egg_roles
+        set_forward_revenue
+        set_holder_discounts
+        set_holder_sale
+        set_payees
+        set_phase_allocation
+        set_referral_program
+        set_vesting_schedule
+        set_whitelist_ends
+        sign_withdrawal
         timestamp_first_whitelist
+        timestamp_first_whitelist_end
+        timestamp_holder_sale
         timestamp_public_sale
         timestamp_sale_closed
         timestamp_second_whitelist
+        timestamp_second_whitelist_end
+        unpause
+        callBack
     )
 }
-
-elrond_wasm_node::wasm_empty_callback! {}